@@ -0,0 +1,281 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+/// A disjoint set (union-find) over dense indices, with path compression and union by rank.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// Returns a new [`DisjointSet`] where each of the `len` elements is in its own set.
+    pub fn new(len: usize) -> Self {
+        Self { parents: (0..len).collect(), ranks: vec![0; len], sizes: vec![1; len], count: len }
+    }
+
+    /// Adds a new element in its own set, returning its index.
+    pub fn add(&mut self) -> usize {
+        let idx = self.parents.len();
+        self.parents.push(idx);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.count += 1;
+        idx
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns true if there are no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the representative of the set containing an element.
+    pub fn find(&mut self, idx: usize) -> usize {
+        let mut root = idx;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut idx = idx;
+        while self.parents[idx] != root {
+            idx = core::mem::replace(&mut self.parents[idx], root);
+        }
+        root
+    }
+
+    /// Merges the sets containing two elements. Returns false if they were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (parent, child) = if self.ranks[a] < self.ranks[b] { (b, a) } else { (a, b) };
+        self.parents[child] = parent;
+        self.sizes[parent] += self.sizes[child];
+        if self.ranks[parent] == self.ranks[child] {
+            self.ranks[parent] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    /// Returns whether two elements are in the same set.
+    #[inline]
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing an element.
+    #[inline]
+    pub fn size(&mut self, idx: usize) -> usize {
+        let root = self.find(idx);
+        self.sizes[root]
+    }
+
+    /// Returns a label for each element, numbering the sets from 0 in order of their first element.
+    pub fn labels(&mut self) -> Vec<usize> {
+        let mut roots = vec![usize::MAX; self.len()];
+        let mut next = 0;
+        (0..self.len())
+            .map(|idx| {
+                let root = self.find(idx);
+                if roots[root] == usize::MAX {
+                    roots[root] = next;
+                    next += 1;
+                }
+                roots[root]
+            })
+            .collect()
+    }
+
+    /// Returns the members of each set, in order of their first element.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut result = vec![Vec::new(); self.count];
+        for (idx, label) in self.labels().into_iter().enumerate() {
+            result[label].push(idx);
+        }
+        result
+    }
+}
+
+/// A disjoint set (union-find) over hashable keys.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+pub struct KeyedDisjointSet<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    set: DisjointSet,
+}
+
+#[cfg(feature = "std")]
+impl<K: Clone + Eq + Hash> KeyedDisjointSet<K> {
+    /// Returns a new empty [`KeyedDisjointSet`].
+    #[inline]
+    pub fn new() -> Self {
+        Self { indices: HashMap::new(), keys: Vec::new(), set: DisjointSet::default() }
+    }
+
+    /// Adds a key in its own set if it is not present, returning its index.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&idx) = self.indices.get(&key) {
+            return idx;
+        }
+
+        let idx = self.set.add();
+        self.indices.insert(key.clone(), idx);
+        self.keys.push(key);
+        idx
+    }
+
+    /// Returns whether a key is present.
+    #[inline]
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Returns the number of keys.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns true if there are no keys.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.set.count()
+    }
+
+    /// Returns the representative of the set containing a key, or [`None`] if it is not present.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let idx = *self.indices.get(key)?;
+        let root = self.set.find(idx);
+        Some(&self.keys[root])
+    }
+
+    /// Merges the sets containing two keys, adding them if needed. Returns false if already in the same set.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.set.union(a, b)
+    }
+
+    /// Returns whether two keys are in the same set. Keys which are not present are not connected.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(&a), Some(&b)) => self.set.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// Returns the size of the set containing a key, or [`None`] if it is not present.
+    pub fn size(&mut self, key: &K) -> Option<usize> {
+        let idx = *self.indices.get(key)?;
+        Some(self.set.size(idx))
+    }
+
+    /// Returns the members of each set, in order of insertion of their first key.
+    pub fn groups(&mut self) -> Vec<Vec<K>> {
+        self.set
+            .groups()
+            .into_iter()
+            .map(|group| group.into_iter().map(|idx| self.keys[idx].clone()).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sut = DisjointSet::new(5);
+        assert_eq!(sut.count(), 5);
+        assert!(sut.union(0, 1));
+        assert!(sut.union(3, 4));
+        assert!(sut.union(1, 4));
+        assert!(!sut.union(0, 3));
+        assert_eq!(sut.count(), 2);
+        assert_eq!(sut.find(0), sut.find(4));
+        assert!(sut.connected(1, 3));
+        assert!(!sut.connected(2, 3));
+        assert_eq!(sut.size(4), 4);
+        assert_eq!(sut.size(2), 1);
+    }
+
+    #[test]
+    fn test_add() {
+        let mut sut = DisjointSet::default();
+        assert!(sut.is_empty());
+        assert_eq!(sut.add(), 0);
+        assert_eq!(sut.add(), 1);
+        assert!(sut.union(0, 1));
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut.count(), 1);
+    }
+
+    #[test]
+    fn test_path_compression() {
+        let mut sut = DisjointSet::new(4);
+        sut.parents = vec![0, 0, 1, 2];
+        assert_eq!(sut.find(3), 0);
+        assert_eq!(sut.parents, vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_groups() {
+        let mut sut = DisjointSet::new(6);
+        sut.union(5, 1);
+        sut.union(2, 4);
+        sut.union(4, 5);
+        assert_eq!(sut.labels(), vec![0, 1, 1, 2, 1, 1]);
+        assert_eq!(sut.groups(), vec![vec![0], vec![1, 2, 4, 5], vec![3]]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_keyed() {
+        let mut sut = KeyedDisjointSet::new();
+        assert!(sut.union("a", "b"));
+        assert!(sut.union("c", "d"));
+        assert!(!sut.union("b", "a"));
+        sut.insert("e");
+        assert_eq!(sut.len(), 5);
+        assert_eq!(sut.count(), 3);
+        assert!(sut.connected(&"a", &"b"));
+        assert!(!sut.connected(&"a", &"c"));
+        assert!(!sut.connected(&"a", &"z"));
+        let root = sut.find(&"a").copied();
+        assert_eq!(sut.find(&"b").copied(), root);
+        assert_eq!(sut.find(&"z"), None);
+        assert_eq!(sut.size(&"d"), Some(2));
+        assert_eq!(sut.groups(), vec![vec!["a", "b"], vec!["c", "d"], vec!["e"]]);
+    }
+}
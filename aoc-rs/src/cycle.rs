@@ -0,0 +1,188 @@
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+/// The shape of the sequence `x, f(x), f(f(x)), ...` once its states start repeating.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Cycle {
+    /// The number of steps before the first state which repeats.
+    pub start: usize,
+
+    /// The number of steps between repeats of a state.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Returns the earliest step which has the same state as step `n`.
+    #[inline]
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Finds the [`Cycle`] of `x -> f(x)` using [Floyd's algorithm](https://en.wikipedia.org/wiki/Cycle_detection).
+/// Never returns if the states do not repeat.
+pub fn floyd<T: PartialEq>(start: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = f(&start);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        hare = f(&hare);
+    }
+
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        period += 1;
+    }
+    Cycle { start: prefix, period }
+}
+
+/// Finds the [`Cycle`] of `x -> f(x)` using [Brent's algorithm](https://en.wikipedia.org/wiki/Cycle_detection),
+/// which calls `f` fewer times than [`floyd`]. Never returns if the states do not repeat.
+pub fn brent<T: Clone + PartialEq>(start: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = f(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = f(&hare);
+        period += 1;
+    }
+
+    let mut prefix = 0;
+    let mut tortoise = start.clone();
+    let mut hare = (0..period).fold(start, |state, _| f(&state));
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+    Cycle { start: prefix, period }
+}
+
+/// Returns the state after `n` steps of `x -> f(x)`, skipping whole cycles found by [`brent`]. Never returns if the
+/// states do not repeat.
+pub fn nth<T: Clone + PartialEq>(start: T, mut f: impl FnMut(&T) -> T, n: usize) -> T {
+    let cycle = brent(start.clone(), &mut f);
+    (0..cycle.reduce(n)).fold(start, |state, _| f(&state))
+}
+
+/// Finds the [`Cycle`] of `x -> f(x)` by remembering the step at which each state was first seen. Calls `f` once per
+/// step, at the cost of storing every state. Never returns if the states do not repeat.
+#[cfg(feature = "std")]
+pub fn hashed<T: Clone + Eq + Hash>(start: T, mut f: impl FnMut(&T) -> T) -> Cycle {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut state = start;
+    let mut step = 0;
+    loop {
+        if let Some(&first) = seen.get(&state) {
+            return Cycle { start: first, period: step - first };
+        }
+
+        let next = f(&state);
+        seen.insert(state, step);
+        state = next;
+        step += 1;
+    }
+}
+
+/// Returns the state after `n` steps of `x -> f(x)`, skipping whole cycles once a state repeats. Stops early if step
+/// `n` is reached before any state repeats.
+#[cfg(feature = "std")]
+pub fn nth_hashed<T: Clone + Eq + Hash>(start: T, mut f: impl FnMut(&T) -> T, n: usize) -> T {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    for step in 0..n {
+        if let Some(&first) = seen.get(&state) {
+            let cycle = Cycle { start: first, period: step - first };
+            return states.swap_remove(cycle.reduce(n));
+        }
+
+        let next = f(&state);
+        seen.insert(state.clone(), step);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 2, 3, 4, 5, ...
+    fn step(x: &u32) -> u32 {
+        if *x < 5 { x + 1 } else { 2 }
+    }
+
+    #[test]
+    fn test_reduce() {
+        let sut = Cycle { start: 2, period: 4 };
+        assert_eq!(sut.reduce(1), 1);
+        assert_eq!(sut.reduce(5), 5);
+        assert_eq!(sut.reduce(6), 2);
+        assert_eq!(sut.reduce(100), 4);
+    }
+
+    #[test]
+    fn test_floyd() {
+        assert_eq!(floyd(0, step), Cycle { start: 2, period: 4 });
+        assert_eq!(floyd(0, |x| (x + 1) % 3), Cycle { start: 0, period: 3 });
+        assert_eq!(floyd(7, |&x| x), Cycle { start: 0, period: 1 });
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(0, step), Cycle { start: 2, period: 4 });
+        assert_eq!(brent(0, |x| (x + 1) % 3), Cycle { start: 0, period: 3 });
+        assert_eq!(brent(7, |&x| x), Cycle { start: 0, period: 1 });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hashed() {
+        assert_eq!(hashed(0, step), Cycle { start: 2, period: 4 });
+        assert_eq!(hashed(0, |x| (x + 1) % 3), Cycle { start: 0, period: 3 });
+        assert_eq!(hashed(7, |&x| x), Cycle { start: 0, period: 1 });
+    }
+
+    #[test]
+    fn test_nth() {
+        assert_eq!(nth(0, step, 1), 1);
+        assert_eq!(nth(0, step, 6), 2);
+        assert_eq!(nth(0, step, 1_000_000_000), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_nth_hashed() {
+        assert_eq!(nth_hashed(0, step, 0), 0);
+        assert_eq!(nth_hashed(0, step, 6), 2);
+        assert_eq!(nth_hashed(0, step, 1_000_000_000), 4);
+
+        let mut calls = 0;
+        nth_hashed(0, |x| { calls += 1; step(x) }, 1_000_000_000);
+        assert_eq!(calls, 6);
+    }
+}
@@ -0,0 +1,287 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::convert::Infallible;
+use core::fmt::{Display, Formatter};
+use core::num::{IntErrorKind, ParseIntError};
+
+/// The reason why parsing failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Reason {
+    /// The input is empty.
+    Empty,
+
+    /// The text is not a valid integer.
+    InvalidInt(IntErrorKind),
+
+    /// A row has a different width to the first row.
+    UnevenRow { expected: usize, actual: usize },
+
+    /// Something is missing, such as a separator or a field.
+    Missing(&'static str),
+
+    /// The text is not a valid value.
+    Invalid(&'static str),
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Reason::Empty => write!(f, "empty input"),
+            Reason::InvalidInt(kind) => match kind {
+                IntErrorKind::Empty => write!(f, "invalid integer (empty)"),
+                IntErrorKind::InvalidDigit => write!(f, "invalid integer (invalid digit)"),
+                IntErrorKind::PosOverflow => write!(f, "invalid integer (too large)"),
+                IntErrorKind::NegOverflow => write!(f, "invalid integer (too small)"),
+                _ => write!(f, "invalid integer"),
+            },
+            Reason::UnevenRow { expected, actual } => write!(f, "row has width {}, expected {}", actual, expected),
+            Reason::Missing(what) => write!(f, "missing {}", what),
+            Reason::Invalid(what) => write!(f, "invalid {}", what),
+        }
+    }
+}
+
+/// An error returned by this crate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Parsing text failed. Line and column numbers start from 1.
+    Parse { line: Option<usize>, column: Option<usize>, text: String, reason: Reason },
+
+    /// A grid's data does not fit its width.
+    GridDimension { width: usize, len: usize },
+
+    /// A block of `len` values does not fill `width` by `height` cells, or the block has no cells.
+    BlockDimension { width: usize, height: usize, len: usize },
+
+    /// An area's maximum is less than its minimum.
+    AreaBoundary,
+
+    /// A volume's maximum is less than its minimum.
+    VolumeBoundary,
+
+    /// A puzzle input has no answer for a part.
+    NoAnswer(&'static str),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Parse { line, column, text, reason } => {
+                match (line, column) {
+                    (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+                    (Some(line), None) => write!(f, "line {}: ", line)?,
+                    (None, Some(column)) => write!(f, "column {}: ", column)?,
+                    (None, None) => (),
+                }
+                write!(f, "{}", reason)?;
+                if !text.is_empty() {
+                    write!(f, " in `{}`", text)?;
+                }
+                Ok(())
+            },
+            Error::GridDimension { width, len } => write!(f, "{} cells do not fit a grid of width {}", len, width),
+            Error::BlockDimension { width, height, .. } if width * height == 0 => {
+                write!(f, "block of {} by {} cells is empty", width, height)
+            },
+            Error::BlockDimension { width, height, len } => {
+                write!(f, "{} values do not fill a block of {} by {} cells", len, width, height)
+            },
+            Error::AreaBoundary => write!(f, "area maximum is less than its minimum"),
+            Error::VolumeBoundary => write!(f, "volume maximum is less than its minimum"),
+            Error::NoAnswer(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    #[inline]
+    fn from(value: ParseIntError) -> Self {
+        Error::Parse { line: None, column: None, text: String::new(), reason: Reason::InvalidInt(*value.kind()) }
+    }
+}
+
+impl From<Infallible> for Error {
+    #[inline]
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+impl Error {
+    /// Returns a new parse [`Error`] for some text.
+    #[inline]
+    pub fn parse(text: &str, reason: Reason) -> Self {
+        Error::Parse { line: None, column: None, text: String::from(text), reason }
+    }
+
+    /// Sets the line number of a parse [`Error`], unless it is already set.
+    #[inline]
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            Error::Parse { line: None, column, text, reason } => {
+                Error::Parse { line: Some(line), column, text, reason }
+            },
+            _ => self,
+        }
+    }
+
+    /// Sets the column number of a parse [`Error`], unless it is already set.
+    #[inline]
+    pub fn at_column(self, column: usize) -> Self {
+        match self {
+            Error::Parse { line, column: None, text, reason } => {
+                Error::Parse { line, column: Some(column), text, reason }
+            },
+            _ => self,
+        }
+    }
+
+    /// Offsets the line number of a parse [`Error`] found in a section of text starting at `line`.
+    #[inline]
+    pub fn offset_line(self, line: usize) -> Self {
+        match self {
+            Error::Parse { line: inner, column, text, reason } => {
+                Error::Parse { line: Some(inner.map_or(line, |x| x + line - 1)), column, text, reason }
+            },
+            _ => self,
+        }
+    }
+
+    /// Offsets the column number of a parse [`Error`] found in part of `text` starting at `column`, and replaces its
+    /// text with `text`.
+    #[inline]
+    pub fn offset_column(self, text: &str, column: usize) -> Self {
+        match self {
+            Error::Parse { line, column: inner, reason, .. } => Error::Parse {
+                line,
+                column: Some(inner.map_or(column, |x| x + column - 1)),
+                text: String::from(text),
+                reason,
+            },
+            _ => self,
+        }
+    }
+
+    /// Sets the offending text of a parse [`Error`], unless it is already set.
+    #[inline]
+    pub fn with_text(self, text: &str) -> Self {
+        match self {
+            Error::Parse { line, column, text: t, reason } if t.is_empty() => {
+                Error::Parse { line, column, text: String::from(text), reason }
+            },
+            _ => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::format;
+    use core::str::FromStr;
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let sut = Error::parse("abc", Reason::Invalid("digit"));
+        assert_eq!(format!("{}", sut), "invalid digit in `abc`");
+
+        let sut = Error::parse("abc", Reason::Missing("separator `,`")).at_line(3);
+        assert_eq!(format!("{}", sut), "line 3: missing separator `,` in `abc`");
+
+        let sut = Error::parse("", Reason::Empty).at_line(1).at_column(5);
+        assert_eq!(format!("{}", sut), "line 1, column 5: empty input");
+
+        let sut = Error::parse("12\n3", Reason::UnevenRow { expected: 2, actual: 1 }).at_line(2);
+        assert_eq!(format!("{}", sut), "line 2: row has width 1, expected 2 in `12\n3`");
+
+        let sut = Error::GridDimension { width: 3, len: 8 };
+        assert_eq!(format!("{}", sut), "8 cells do not fit a grid of width 3");
+
+        let sut = Error::AreaBoundary;
+        assert_eq!(format!("{}", sut), "area maximum is less than its minimum");
+
+        let sut = Error::VolumeBoundary;
+        assert_eq!(format!("{}", sut), "volume maximum is less than its minimum");
+
+        let sut = Error::NoAnswer("no path");
+        assert_eq!(format!("{}", sut), "no answer: no path");
+    }
+
+    #[test]
+    fn test_from_parse_int_error() {
+        let sut = Error::from(i32::from_str("x").unwrap_err());
+        let expected = Reason::InvalidInt(IntErrorKind::InvalidDigit);
+        assert_eq!(sut, Error::Parse { line: None, column: None, text: String::new(), reason: expected });
+        assert_eq!(format!("{}", sut), "invalid integer (invalid digit)");
+
+        let sut = Error::from(u8::from_str("256").unwrap_err()).with_text("256");
+        assert_eq!(format!("{}", sut), "invalid integer (too large) in `256`");
+    }
+
+    #[test]
+    fn test_question_mark() {
+        fn parse(s: &str) -> Result<i32, Error> {
+            Ok(i32::from_str(s)?)
+        }
+
+        assert_eq!(parse("42"), Ok(42));
+        assert!(parse("4x2").is_err());
+    }
+
+    #[test]
+    fn test_at_line() {
+        let sut = Error::parse("x", Reason::Empty).at_line(1).at_line(2);
+        assert_eq!(sut, Error::Parse { line: Some(1), column: None, text: String::from("x"), reason: Reason::Empty });
+
+        let sut = Error::AreaBoundary.at_line(1);
+        assert_eq!(sut, Error::AreaBoundary);
+    }
+
+    #[test]
+    fn test_at_column() {
+        let sut = Error::parse("x", Reason::Empty).at_column(4).at_column(2);
+        assert_eq!(sut, Error::Parse { line: None, column: Some(4), text: String::from("x"), reason: Reason::Empty });
+    }
+
+    #[test]
+    fn test_offset_line() {
+        let sut = Error::parse("x", Reason::Empty).at_line(2).offset_line(5);
+        assert_eq!(sut, Error::parse("x", Reason::Empty).at_line(6));
+
+        let sut = Error::parse("x", Reason::Empty).offset_line(5);
+        assert_eq!(sut, Error::parse("x", Reason::Empty).at_line(5));
+    }
+
+    #[test]
+    fn test_offset_column() {
+        let sut = Error::parse("x", Reason::Empty).at_column(2).offset_column("ab x", 3);
+        assert_eq!(format!("{}", sut), "column 4: empty input in `ab x`");
+
+        let sut = Error::parse("x", Reason::Empty).offset_column("ab x", 3);
+        assert_eq!(sut, Error::parse("ab x", Reason::Empty).at_column(3));
+    }
+
+    #[test]
+    fn test_with_text() {
+        let sut = Error::from(i32::from_str("").unwrap_err()).with_text("a").with_text("b");
+        assert_eq!(format!("{}", sut), "invalid integer (empty) in `a`");
+    }
+
+    #[test]
+    fn test_block_dimension() {
+        let sut = Error::BlockDimension { width: 2, height: 2, len: 1 };
+        assert_eq!(format!("{}", sut), "1 values do not fill a block of 2 by 2 cells");
+        let sut = Error::BlockDimension { width: 0, height: 2, len: 0 };
+        assert_eq!(format!("{}", sut), "block of 0 by 2 cells is empty");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_error_trait() {
+        let sut: Box<dyn std::error::Error> = Box::new(Error::AreaBoundary);
+        assert_eq!(sut.to_string(), "area maximum is less than its minimum");
+    }
+}
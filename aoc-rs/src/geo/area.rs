@@ -1,12 +1,9 @@
 use num::{CheckedAdd, CheckedSub, Num};
+use crate::error::Error;
 use crate::geo::area_iter::AreaIterator;
 use crate::geo::direction::Direction;
 use crate::geo::pos::Pos;
 
-/// An error returned when [`Area<T>`]'s dimension is invalid.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct AreaBoundaryError;
-
 /// A 2D area.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Area<T> {
@@ -28,9 +25,9 @@ impl<T: Copy + Num + PartialOrd> IntoIterator for Area<T> {
 
 impl<T: PartialOrd> Area<T> {
     /// Returns a new [`Area<T>`].
-    pub fn new(max_x: T, max_y: T, min_x: T, min_y: T) -> Result<Self, AreaBoundaryError> {
+    pub fn new(max_x: T, max_y: T, min_x: T, min_y: T) -> Result<Self, Error> {
         if max_x < min_x || max_y < min_y {
-            return Err(AreaBoundaryError);
+            return Err(Error::AreaBoundary);
         }
         Ok(Self { max_x, max_y, min_x, min_y })
     }
@@ -100,9 +97,9 @@ impl<T: Copy + Num> Area<T> {
 
 impl<T: Copy + PartialOrd> Area<T> {
     /// Returns an [`Area<T>`] constructed from top left and bottom right [`Pos<T>`]s.
-    pub fn from_pos(top_left: &Pos<T>, bottom_right: &Pos<T>) -> Result<Self, AreaBoundaryError> {
+    pub fn from_pos(top_left: &Pos<T>, bottom_right: &Pos<T>) -> Result<Self, Error> {
         if bottom_right.y > top_left.y || bottom_right.x < top_left.x {
-            return Err(AreaBoundaryError);
+            return Err(Error::AreaBoundary);
         }
         Ok(Self { max_x: bottom_right.x, min_x: top_left.x, max_y: top_left.y, min_y: bottom_right.y })
     }
@@ -174,7 +171,7 @@ mod test {
         assert_eq!(sut.unwrap(), Area { max_x: 0, max_y: 0, min_x: 0, min_y: 0 });
 
         let sut = Area::new(-1, -1, 0, 0);
-        assert!(sut.is_err());
+        assert_eq!(sut, Err(Error::AreaBoundary));
     }
 
     #[test]
@@ -0,0 +1,311 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Index;
+use crate::geo::area::Area;
+use crate::geo::direction::{Direction, DirectionalPos};
+use crate::geo::grid::Grid;
+use crate::geo::pos::{Pos, PosIdx};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Represents a 2D rectangular grid of booleans packed into bits, with the same dimensions and screen coordinates as
+/// [`Grid<T>`]. Useful as a visited set which does not need hashing.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BitGrid {
+    pub width: usize,
+    pub height: usize,
+    words: Vec<u64>,
+}
+
+impl Index<PosIdx> for BitGrid {
+    type Output = bool;
+
+    #[inline]
+    fn index(&self, index: PosIdx) -> &Self::Output {
+        if self.get(&index) { &true } else { &false }
+    }
+}
+
+impl BitGrid {
+    /// Returns a new [`BitGrid`] with every cell unset.
+    #[inline]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height, words: vec![0; (width * height).div_ceil(WORD_BITS)] }
+    }
+
+    /// Returns a new [`BitGrid`] with every cell unset, with the same dimensions as a [`Grid<T>`].
+    #[inline]
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid.width, grid.height)
+    }
+
+    /// Returns whether a [`PosIdx`] is set.
+    #[inline]
+    pub fn get(&self, pos: &PosIdx) -> bool {
+        let idx = self.idx(pos);
+        self.words[idx / WORD_BITS] & (1 << (idx % WORD_BITS)) != 0
+    }
+
+    /// Sets or unsets a [`PosIdx`].
+    #[inline]
+    pub fn set(&mut self, pos: &PosIdx, value: bool) {
+        if value {
+            self.insert(pos);
+        } else {
+            self.remove(pos);
+        }
+    }
+
+    /// Sets a [`PosIdx`]. Returns false if it was already set.
+    #[inline]
+    pub fn insert(&mut self, pos: &PosIdx) -> bool {
+        let idx = self.idx(pos);
+        let word = &mut self.words[idx / WORD_BITS];
+        let mask = 1 << (idx % WORD_BITS);
+        let result = *word & mask == 0;
+        *word |= mask;
+        result
+    }
+
+    /// Unsets a [`PosIdx`]. Returns false if it was not set.
+    #[inline]
+    pub fn remove(&mut self, pos: &PosIdx) -> bool {
+        let idx = self.idx(pos);
+        let word = &mut self.words[idx / WORD_BITS];
+        let mask = 1 << (idx % WORD_BITS);
+        let result = *word & mask != 0;
+        *word &= !mask;
+        result
+    }
+
+    /// Unsets every cell.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Returns the number of set cells.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over the set cells, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = PosIdx> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let mut word = word;
+            core::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let idx = i * WORD_BITS + word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(Pos { x: idx % self.width, y: idx / self.width })
+            })
+        })
+    }
+
+    /// Returns an `Area<usize>` which bounds this [`BitGrid`].
+    #[inline]
+    pub fn area(&self) -> Area<usize> {
+        Area { max_x: self.width - 1, max_y: self.height - 1, min_x: 0, min_y: 0 }
+    }
+
+    /// Returns whether this [`BitGrid`] has a [`PosIdx`].
+    #[inline]
+    pub fn has(&self, pos: &PosIdx) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    /// Returns the size of this [`BitGrid`].
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.width * self.height
+    }
+
+    #[inline]
+    fn idx(&self, pos: &PosIdx) -> usize {
+        debug_assert!(self.has(pos), "{:?} is outside the grid", pos);
+        self.width * pos.y + pos.x
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut result = Self::for_grid(grid);
+        for pos in grid.area() {
+            if grid[pos] {
+                result.insert(&pos);
+            }
+        }
+        result
+    }
+}
+
+/// Represents a 2D rectangular grid with one bit per [`Direction`] in each cell. Useful as a visited set of
+/// [`DirectionalPos<usize>`] states which does not need hashing.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DirectionalBitGrid {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<u8>,
+}
+
+impl DirectionalBitGrid {
+    /// Returns a new [`DirectionalBitGrid`] with every state unset.
+    #[inline]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height, cells: vec![0; width * height] }
+    }
+
+    /// Returns a new [`DirectionalBitGrid`] with every state unset, with the same dimensions as a [`Grid<T>`].
+    #[inline]
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid.width, grid.height)
+    }
+
+    /// Returns whether a [`DirectionalPos<usize>`] is set.
+    #[inline]
+    pub fn contains(&self, dpos: &DirectionalPos<usize>) -> bool {
+        self.cells[self.idx(&dpos.pos)] & Self::mask(dpos.direction) != 0
+    }
+
+    /// Sets a [`DirectionalPos<usize>`]. Returns false if it was already set.
+    #[inline]
+    pub fn insert(&mut self, dpos: &DirectionalPos<usize>) -> bool {
+        let idx = self.idx(&dpos.pos);
+        let mask = Self::mask(dpos.direction);
+        let result = self.cells[idx] & mask == 0;
+        self.cells[idx] |= mask;
+        result
+    }
+
+    /// Unsets a [`DirectionalPos<usize>`]. Returns false if it was not set.
+    #[inline]
+    pub fn remove(&mut self, dpos: &DirectionalPos<usize>) -> bool {
+        let idx = self.idx(&dpos.pos);
+        let mask = Self::mask(dpos.direction);
+        let result = self.cells[idx] & mask != 0;
+        self.cells[idx] &= !mask;
+        result
+    }
+
+    /// Returns the [`Direction`]s set at a [`PosIdx`].
+    #[inline]
+    pub fn directions(&self, pos: &PosIdx) -> impl Iterator<Item = Direction> {
+        let cell = self.cells[self.idx(pos)];
+        Direction::all().into_iter().filter(move |&dir| cell & Self::mask(dir) != 0)
+    }
+
+    /// Unsets every state.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cells.fill(0);
+    }
+
+    /// Returns the number of set states.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.cells.iter().map(|cell| cell.count_ones() as usize).sum()
+    }
+
+    /// Returns a [`BitGrid`] of the cells with any [`Direction`] set.
+    pub fn positions(&self) -> BitGrid {
+        let mut result = BitGrid::new(self.width, self.height);
+        for (idx, _) in self.cells.iter().enumerate().filter(|(_, &cell)| cell != 0) {
+            result.insert(&Pos { x: idx % self.width, y: idx / self.width });
+        }
+        result
+    }
+
+    /// Returns the size of this [`DirectionalBitGrid`].
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.width * self.height
+    }
+
+    #[inline]
+    fn idx(&self, pos: &PosIdx) -> usize {
+        debug_assert!(pos.x < self.width && pos.y < self.height, "{:?} is outside the grid", pos);
+        self.width * pos.y + pos.x
+    }
+
+    #[inline]
+    fn mask(direction: Direction) -> u8 {
+        1 << direction as u8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut sut = BitGrid::new(10, 10);
+        assert!(sut.insert(&Pos { x: 3, y: 7 }));
+        assert!(!sut.insert(&Pos { x: 3, y: 7 }));
+        assert!(sut.get(&Pos { x: 3, y: 7 }));
+        assert!(sut[Pos { x: 3, y: 7 }]);
+        assert!(!sut[Pos { x: 7, y: 3 }]);
+        assert_eq!(sut.count(), 1);
+
+        assert!(sut.remove(&Pos { x: 3, y: 7 }));
+        assert!(!sut.remove(&Pos { x: 3, y: 7 }));
+        sut.set(&Pos { x: 9, y: 9 }, true);
+        sut.set(&Pos { x: 0, y: 0 }, true);
+        sut.set(&Pos { x: 0, y: 0 }, false);
+        assert_eq!(sut.count(), 1);
+        sut.clear();
+        assert_eq!(sut.count(), 0);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut sut = BitGrid::new(13, 11);
+        let positions = [Pos { x: 12, y: 0 }, Pos { x: 0, y: 5 }, Pos { x: 1, y: 5 }, Pos { x: 12, y: 10 }];
+        for pos in &positions {
+            sut.insert(pos);
+        }
+        assert_eq!(sut.iter().collect::<Vec<_>>(), positions.to_vec());
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let grid = Grid::new(4, 3, b'.').unwrap();
+        let sut = BitGrid::for_grid(&grid);
+        assert_eq!(sut.area(), grid.area());
+        assert_eq!(sut.size(), 12);
+        assert!(sut.has(&Pos { x: 3, y: 2 }));
+        assert!(!sut.has(&Pos { x: 4, y: 2 }));
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::with_data(2, &vec![true, false, false, true]).unwrap();
+        let sut = BitGrid::from(&grid);
+        assert_eq!(sut.iter().collect::<Vec<_>>(), vec![Pos { x: 0, y: 0 }, Pos { x: 1, y: 1 }]);
+    }
+
+    #[test]
+    fn test_directional() {
+        let mut sut = DirectionalBitGrid::new(5, 5);
+        let up = DirectionalPos::new(Pos { x: 2, y: 3 }, Direction::Up);
+        let left = DirectionalPos::new(Pos { x: 2, y: 3 }, Direction::Left);
+        assert!(sut.insert(&up));
+        assert!(!sut.insert(&up));
+        assert!(sut.insert(&left));
+        assert!(sut.contains(&up));
+        assert!(!sut.contains(&DirectionalPos::new(Pos { x: 2, y: 3 }, Direction::Down)));
+        assert_eq!(sut.directions(&Pos { x: 2, y: 3 }).collect::<Vec<_>>(), vec![Direction::Up, Direction::Left]);
+        assert_eq!(sut.count(), 2);
+        assert_eq!(sut.positions().iter().collect::<Vec<_>>(), vec![Pos { x: 2, y: 3 }]);
+
+        assert!(sut.remove(&up));
+        assert!(!sut.contains(&up));
+        sut.clear();
+        assert_eq!(sut.count(), 0);
+    }
+}
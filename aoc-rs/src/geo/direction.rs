@@ -18,7 +18,7 @@ pub enum Direction {
 
 impl Display for Direction {
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             Direction::Up => write!(f, "up (north)"),
             Direction::Down => write!(f, "down (south)"),
@@ -87,6 +87,21 @@ impl Direction {
         }
     }
 
+    /// Returns the [`Direction`] with its vertical component reversed, converting between math (y-up) and screen
+    /// (y-down) coordinates.
+    #[inline]
+    pub fn flip_y(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::TopLeft => Direction::BottomLeft,
+            Direction::TopRight => Direction::BottomRight,
+            Direction::BottomLeft => Direction::TopLeft,
+            Direction::BottomRight => Direction::TopRight,
+            _ => *self,
+        }
+    }
+
     /// Returns the left [`Direction`] relative to the current [`Direction`].
     #[inline]
     pub fn left(&self) -> Direction {
@@ -151,6 +166,13 @@ impl<T: Copy + Num> DirectionalPos<T> {
         Self { pos: self.pos.dest(distance, self.direction), direction: self.direction }
     }
 
+    /// Returns the [`DirectionalPos<T>`] next to this [`DirectionalPos<T>`] in screen coordinates. See
+    /// [`Pos::screen_dest`].
+    #[inline]
+    pub fn screen_next(&self, distance: T) -> Self {
+        Self { pos: self.pos.screen_dest(distance, self.direction), direction: self.direction }
+    }
+
     /// Returns the `DirectionPos<T>` with a new direction.
     #[inline]
     pub fn update_direction(&self, direction: Direction) -> Self {
@@ -164,6 +186,13 @@ impl<T: Copy + Num + CheckedAdd + CheckedSub> DirectionalPos<T> {
     pub fn checked_next(&self, distance: T) -> Option<Self> {
         Some(Self { pos: self.pos.checked_dest(distance, self.direction)?, direction: self.direction })
     }
+
+    /// Returns the [`DirectionalPos<T>`] next to this [`DirectionalPos<T>`] in screen coordinates. [`None`] when
+    /// arithmetic overflow.
+    #[inline]
+    pub fn checked_screen_next(&self, distance: T) -> Option<Self> {
+        Some(Self { pos: self.pos.checked_screen_dest(distance, self.direction)?, direction: self.direction })
+    }
 }
 
 impl<T> DirectionalPos<T> {
@@ -242,6 +271,18 @@ mod test {
         assert_eq!(Direction::BottomRight.back(), Direction::TopLeft);
     }
 
+    #[test]
+    fn test_flip_y() {
+        assert_eq!(Direction::Up.flip_y(), Direction::Down);
+        assert_eq!(Direction::Down.flip_y(), Direction::Up);
+        assert_eq!(Direction::Left.flip_y(), Direction::Left);
+        assert_eq!(Direction::Right.flip_y(), Direction::Right);
+        assert_eq!(Direction::TopLeft.flip_y(), Direction::BottomLeft);
+        assert_eq!(Direction::TopRight.flip_y(), Direction::BottomRight);
+        assert_eq!(Direction::BottomLeft.flip_y(), Direction::TopLeft);
+        assert_eq!(Direction::BottomRight.flip_y(), Direction::TopRight);
+    }
+
     #[test]
     fn test_left() {
         assert_eq!(Direction::Up.left(), Direction::Left);
@@ -304,6 +345,16 @@ mod test {
         assert!(sut.is_none());
     }
 
+    #[test]
+    fn test_screen_next() {
+        let p = DirectionalPos { pos: Pos { x: 0, y: 0 }, direction: Direction::Up };
+        assert_eq!(p.screen_next(3).pos, Pos { x: 0, y: -3 });
+
+        let p = DirectionalPos { pos: PosIdx { x: 0, y: 0 }, direction: Direction::Down };
+        assert_eq!(p.checked_screen_next(3).unwrap().pos, Pos { x: 0, y: 3 });
+        assert!(p.update_direction(Direction::Up).checked_screen_next(1).is_none());
+    }
+
     #[test]
     fn new_pos_direction() {
         let p = DirectionalPos { pos: Pos { x: 0, y: 0 }, direction: Direction::TopLeft };
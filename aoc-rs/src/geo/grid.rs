@@ -9,21 +9,17 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::string::FromUtf8Error;
 use core::fmt::Display;
+use core::iter::repeat_n;
 use core::ops::{Index, IndexMut};
 use core::str::FromStr;
+use crate::collections::DisjointSet;
+use crate::error::{Error, Reason};
 use crate::geo::area::Area;
 use crate::geo::direction::Direction;
 use crate::geo::pos::{Pos, PosIdx};
 
-/// An error returned when a [`Grid<T>`] has invalid data dimension.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct GridDimensionError;
-
-/// An error returned when parsing a [`Grid<T>`] fails.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ParseGridError;
-
-/// Represents a 2D rectangular grid.
+/// Represents a 2D rectangular grid. Grids use screen coordinates: row 0 is the top row, so [`Direction::Up`] moves to
+/// the row above.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Grid<T> {
     pub width: usize,
@@ -33,6 +29,35 @@ pub struct Grid<T> {
 
 pub type CharGrid = Grid<u8>;
 
+/// The connected components of a [`Grid<T>`], where cells connect to their orthogonal neighbours.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Components {
+    /// The component of each cell.
+    pub labels: Grid<usize>,
+    /// The cells of each component, in reading order.
+    pub members: Vec<Vec<PosIdx>>,
+}
+
+impl Components {
+    /// Returns the number of components.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns true if there are no components.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Returns the size of each component.
+    #[inline]
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.members.iter().map(Vec::len)
+    }
+}
+
 impl<T: Display> Display for Grid<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for (i, chunk) in self.data.chunks(self.width).enumerate() {
@@ -52,23 +77,24 @@ impl<T: Display> Display for Grid<T> {
 }
 
 impl FromStr for CharGrid {
-    type Err = ParseGridError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(ParseGridError);
+        let s = s.trim_end_matches(['\r', '\n']);
+        let width = s.lines().next().map_or(0, str::len);
+        if width == 0 {
+            return Err(Error::parse(s, Reason::Empty).at_line(1));
         }
 
-        let lines: Vec<_> = s.lines().map(str::as_bytes).collect();
-        let width = lines[0].len();
-        let height = lines.len();
-        let mut data = Vec::with_capacity(width * height);
-        data.extend(lines.iter().flat_map(move |&line| line.iter()));
-
-        if width * height != data.len() {
-            return Err(ParseGridError);
+        let mut data = Vec::with_capacity(s.len());
+        for (idx, line) in s.lines().enumerate() {
+            if line.len() != width {
+                let reason = Reason::UnevenRow { expected: width, actual: line.len() };
+                return Err(Error::parse(line, reason).at_line(idx + 1));
+            }
+            data.extend_from_slice(line.as_bytes());
         }
-        Ok(Self { width, height, data })
+        Ok(Self { width, height: data.len() / width, data })
     }
 }
 
@@ -90,20 +116,106 @@ impl<T> IndexMut<PosIdx> for Grid<T> {
 
 impl<T: Clone> Grid<T> {
     /// Returns a new [`Grid<T>`] with a value.
-    pub fn new(width: usize, height: usize, value: T) -> Result<Self, GridDimensionError> {
+    pub fn new(width: usize, height: usize, value: T) -> Result<Self, Error> {
         if width == 0 || height == 0 {
-            return Err(GridDimensionError);
+            return Err(Error::GridDimension { width, len: width * height });
         }
         Ok(Self { width, height, data: vec![value; width * height] })
     }
 
     /// Returns a new [`Grid<T>`] with initial data.
-    pub fn with_data(width: usize, data: &Vec<T>) -> Result<Self, GridDimensionError> {
-        if width == 0 || data.is_empty() || data.len() % width != 0 {
-            return Err(GridDimensionError);
+    pub fn with_data(width: usize, data: &Vec<T>) -> Result<Self, Error> {
+        if width == 0 || data.is_empty() || !data.len().is_multiple_of(width) {
+            return Err(Error::GridDimension { width, len: data.len() });
         }
         Ok(Self { width, height: data.len() / width, data: (*data).clone() })
     }
+
+    /// Returns a copy of this [`Grid<T>`] rotated a quarter turn clockwise.
+    #[inline]
+    pub fn rotate_cw(&self) -> Self {
+        self.remap(self.height, self.width, |pos| Pos { x: pos.y, y: self.height - 1 - pos.x })
+    }
+
+    /// Returns a copy of this [`Grid<T>`] rotated a quarter turn counterclockwise.
+    #[inline]
+    pub fn rotate_ccw(&self) -> Self {
+        self.remap(self.height, self.width, |pos| Pos { x: self.width - 1 - pos.y, y: pos.x })
+    }
+
+    /// Returns a copy of this [`Grid<T>`] mirrored along its main diagonal, so rows become columns.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |pos| Pos { x: pos.y, y: pos.x })
+    }
+
+    /// Returns a copy of this [`Grid<T>`] mirrored left to right.
+    #[inline]
+    pub fn flip_horizontal(&self) -> Self {
+        self.remap(self.width, self.height, |pos| Pos { x: self.width - 1 - pos.x, y: pos.y })
+    }
+
+    /// Returns a copy of this [`Grid<T>`] mirrored top to bottom.
+    #[inline]
+    pub fn flip_vertical(&self) -> Self {
+        self.remap(self.width, self.height, |pos| Pos { x: pos.x, y: self.height - 1 - pos.y })
+    }
+
+    /// Returns a new [`Grid<T>`] where each cell takes the value of the cell in this grid given by `source`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(PosIdx) -> PosIdx) -> Self {
+        let data = (0..width * height).map(|idx| self[source(Pos { x: idx % width, y: idx / width })].clone());
+        Self { width, height, data: data.collect() }
+    }
+
+    /// Returns a copy of this [`Grid<T>`] surrounded by a border of `size` cells with a value.
+    pub fn pad(&self, size: usize, value: T) -> Self {
+        let width = self.width + 2 * size;
+        let height = self.height + 2 * size;
+        let mut data = Vec::with_capacity(width * height);
+        data.extend(repeat_n(value.clone(), width * size));
+        for row in self.rows() {
+            data.extend(repeat_n(value.clone(), size));
+            data.extend_from_slice(row);
+            data.extend(repeat_n(value.clone(), size));
+        }
+        data.extend(repeat_n(value, width * size));
+        Self { width, height, data }
+    }
+
+    /// Returns a copy of the cells of this [`Grid<T>`] in an `Area<usize>`, or [`None`] if it does not fit.
+    #[inline]
+    pub fn crop(&self, area: Area<usize>) -> Option<Self> {
+        self.view(area).map(|view| view.to_grid())
+    }
+
+    /// Inserts a row with a value before row `y`, shifting the rows below it down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y > height`.
+    pub fn insert_row(&mut self, y: usize, value: T) {
+        assert!(y <= self.height, "row {} is outside the grid", y);
+        let idx = self.width * y;
+        self.data.splice(idx..idx, repeat_n(value, self.width));
+        self.height += 1;
+    }
+
+    /// Inserts a column with a value before column `x`, shifting the columns to its right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x > width`.
+    pub fn insert_column(&mut self, x: usize, value: T) {
+        assert!(x <= self.width, "column {} is outside the grid", x);
+        let mut data = Vec::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            data.extend_from_slice(&row[..x]);
+            data.push(value.clone());
+            data.extend_from_slice(&row[x..]);
+        }
+        self.data = data;
+        self.width += 1;
+    }
 }
 
 impl<T: Copy + PartialEq> Grid<T> {
@@ -135,17 +247,48 @@ impl<T: Copy> Grid<T> {
         positions.into_iter().filter(move |&p| self.has(&p)).map(move |p| self[p])
     }
 
-    /// Returns the destination [`T`], or [`None`] if the destination is out of bounds.
+    /// Returns the destination [`T`] in screen coordinates, or [`None`] if the destination is out of bounds.
     #[inline]
     pub fn destination(&self, pos: &PosIdx, distance: usize, direction: Direction) -> Option<T> {
-        match pos.checked_dest(distance, direction) {
+        match pos.checked_screen_dest(distance, direction) {
             Some(pos) if self.has(&pos) => Some(self[pos]),
             _ => None,
         }
     }
 }
 
+impl<T: PartialEq> Grid<T> {
+    /// Returns the connected components of neighbouring cells with the same value.
+    #[inline]
+    pub fn components(&self) -> Components {
+        self.components_by(|a, b| a == b)
+    }
+}
+
 impl<T> Grid<T> {
+    /// Returns the connected components, where neighbouring cells connect if `connected` returns true.
+    pub fn components_by(&self, mut connected: impl FnMut(&T, &T) -> bool) -> Components {
+        let mut set = DisjointSet::new(self.size());
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let idx = self.width * y + x;
+                if x + 1 < self.width && connected(&self.data[idx], &self.data[idx + 1]) {
+                    set.union(idx, idx + 1);
+                }
+                if y + 1 < self.height && connected(&self.data[idx], &self.data[idx + self.width]) {
+                    set.union(idx, idx + self.width);
+                }
+            }
+        }
+
+        let labels = set.labels();
+        let mut members = vec![Vec::new(); set.count()];
+        for (idx, &label) in labels.iter().enumerate() {
+            members[label].push(Pos { x: idx % self.width, y: idx / self.width });
+        }
+        Components { labels: Grid { width: self.width, height: self.height, data: labels }, members }
+    }
+
     /// Returns an `Area<usize>` which bounds this [`Grid<T>`].
     #[inline]
     pub fn area(&self) -> Area<usize> {
@@ -169,6 +312,131 @@ impl<T> Grid<T> {
     pub fn swap(&mut self, pos: &PosIdx, other: &PosIdx) {
         self.data.swap(self.width * pos.y + pos.x, self.width * other.y + other.x);
     }
+
+    /// Returns a row of this [`Grid<T>`].
+    #[inline]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[self.width * y..self.width * (y + 1)]
+    }
+
+    /// Returns an iterator over the rows of this [`Grid<T>`], from top to bottom.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks_exact(self.width)
+    }
+
+    /// Returns an iterator over a column of this [`Grid<T>`], from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x >= width`.
+    #[inline]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.data[x..].iter().step_by(self.width)
+    }
+
+    /// Returns an iterator over the columns of this [`Grid<T>`], from left to right.
+    #[inline]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns an iterator over the diagonals running from top left to bottom right, starting with the bottom left
+    /// corner and ending with the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width + self.height - 1).map(move |i| {
+            let start = if i < self.height {
+                Pos { x: 0, y: self.height - 1 - i }
+            } else {
+                Pos { x: i + 1 - self.height, y: 0 }
+            };
+            let len = (self.width - start.x).min(self.height - start.y);
+            (0..len).map(move |j| &self[Pos { x: start.x + j, y: start.y + j }])
+        })
+    }
+
+    /// Returns an iterator over the diagonals running from top right to bottom left, starting with the top left
+    /// corner and ending with the bottom right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width + self.height - 1).map(move |i| {
+            let start = if i < self.width {
+                Pos { x: i, y: 0 }
+            } else {
+                Pos { x: self.width - 1, y: i + 1 - self.width }
+            };
+            let len = (start.x + 1).min(self.height - start.y);
+            (0..len).map(move |j| &self[Pos { x: start.x - j, y: start.y + j }])
+        })
+    }
+
+    /// Returns a new [`Grid<U>`] where each cell is replaced by a block of `width` by `height` cells. `block` returns
+    /// the values of each block in reading order.
+    pub fn scale<U, I: IntoIterator<Item = U>>(
+        &self,
+        width: usize,
+        height: usize,
+        mut block: impl FnMut(&T) -> I,
+    ) -> Result<Grid<U>, Error> {
+        let size = width * height;
+        if size == 0 {
+            return Err(Error::BlockDimension { width, height, len: 0 });
+        }
+
+        let mut data = Vec::with_capacity(self.size() * size);
+        for row in self.rows() {
+            let mut blocks = Vec::with_capacity(self.width);
+            for cell in row {
+                let values: Vec<U> = block(cell).into_iter().collect();
+                if values.len() != size {
+                    return Err(Error::BlockDimension { width, height, len: values.len() });
+                }
+                blocks.push(values.into_iter());
+            }
+
+            for _ in 0..height {
+                for values in &mut blocks {
+                    data.extend(values.by_ref().take(width));
+                }
+            }
+        }
+        Ok(Grid { width: self.width * width, height: self.height * height, data })
+    }
+
+    /// Removes row `y`, shifting the rows below it up. Returns the removed values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y >= height`, or if this is the only row.
+    pub fn remove_row(&mut self, y: usize) -> Vec<T> {
+        assert!(y < self.height, "row {} is outside the grid", y);
+        assert!(self.height > 1, "cannot remove the only row of the grid");
+        self.height -= 1;
+        self.data.drain(self.width * y..self.width * (y + 1)).collect()
+    }
+
+    /// Removes column `x`, shifting the columns to its right. Returns the removed values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x >= width`, or if this is the only column.
+    pub fn remove_column(&mut self, x: usize) -> Vec<T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        assert!(self.width > 1, "cannot remove the only column of the grid");
+        let width = self.width;
+        let mut result = Vec::with_capacity(self.height);
+        let mut data = Vec::with_capacity(self.size() - self.height);
+        for (idx, value) in core::mem::take(&mut self.data).into_iter().enumerate() {
+            if idx % width == x {
+                result.push(value);
+            } else {
+                data.push(value);
+            }
+        }
+        self.data = data;
+        self.width -= 1;
+        result
+    }
 }
 
 impl CharGrid {
@@ -198,11 +466,17 @@ mod test {
         let sut: CharGrid = Grid::from_str("123").unwrap();
         assert_eq!(sut, Grid { height: 1, width: 3, data: vec![b'1', b'2', b'3'] });
 
-        let sut: Result<CharGrid, ParseGridError> = Grid::from_str("");
-        assert!(sut.is_err());
+        let sut: Result<CharGrid, Error> = Grid::from_str("");
+        assert_eq!(sut, Err(Error::parse("", Reason::Empty).at_line(1)));
 
-        let sut: Result<CharGrid, ParseGridError> = Grid::from_str("123\n45");
+        let sut: Result<CharGrid, Error> = Grid::from_str("\n123");
         assert!(sut.is_err());
+
+        let sut: CharGrid = Grid::from_str("12\r\n34\r\n\r\n").unwrap();
+        assert_eq!(sut, Grid { height: 2, width: 2, data: vec![b'1', b'2', b'3', b'4'] });
+
+        let sut: Result<CharGrid, Error> = Grid::from_str("123\n45");
+        assert_eq!(sut, Err(Error::parse("45", Reason::UnevenRow { expected: 3, actual: 2 }).at_line(2)));
     }
 
     #[test]
@@ -230,7 +504,7 @@ mod test {
         assert_eq!(sut, Grid { height: 3, width: 3, data: vec![100, 100, 100, 100, 100, 100, 100, 100, 100] });
 
         let sut = Grid::new(0, 0, 100);
-        assert!(sut.is_err());
+        assert_eq!(sut, Err(Error::GridDimension { width: 0, len: 0 }));
     }
 
     #[test]
@@ -242,9 +516,9 @@ mod test {
         assert!(sut.is_err());
 
         let sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6, 7, 8]);
-        assert!(sut.is_err());
+        assert_eq!(sut, Err(Error::GridDimension { width: 3, len: 8 }));
 
-        let sut: Result<Grid<i32>, GridDimensionError> = Grid::with_data(3, &vec![]);
+        let sut: Result<Grid<i32>, Error> = Grid::with_data(3, &vec![]);
         assert!(sut.is_err());
     }
 
@@ -281,8 +555,10 @@ mod test {
     #[test]
     fn test_destination() {
         let sut = Grid { height: 3, width: 3, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] };
-        assert_eq!(sut.destination(&PosIdx { x: 1, y: 1 }, 1, Direction::Up), Some(8));
-        assert_eq!(sut.destination(&PosIdx { x: 1, y: 1 }, 1, Direction::Down), Some(2));
+        assert_eq!(sut.destination(&PosIdx { x: 1, y: 1 }, 1, Direction::Up), Some(2));
+        assert_eq!(sut.destination(&PosIdx { x: 1, y: 1 }, 1, Direction::Down), Some(8));
+        assert_eq!(sut.destination(&PosIdx { x: 1, y: 1 }, 1, Direction::TopLeft), Some(1));
+        assert_eq!(sut.destination(&PosIdx { x: 1, y: 0 }, 1, Direction::Up), None);
         assert_eq!(sut.destination(&PosIdx { x: 1, y: 1 }, 1, Direction::Left), Some(4));
         assert_eq!(sut.destination(&PosIdx { x: 1, y: 1 }, 1, Direction::Right), Some(6));
         assert_eq!(sut.destination(&PosIdx { x: 3, y: 2 }, 1, Direction::Left), Some(9));
@@ -337,4 +613,151 @@ mod test {
         let positions = [Pos { x: 0, y: 0 }, Pos { x: 99, y: 99 }, Pos { x: 1, y: 1 }, Pos { x: 2, y: 2 }];
         assert_eq!(sut.extract_string(positions), Ok(String::from("aei")));
     }
+
+    #[test]
+    fn test_rotate() {
+        let sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(sut.rotate_cw(), Grid { height: 3, width: 2, data: vec![4, 1, 5, 2, 6, 3] });
+        assert_eq!(sut.rotate_ccw(), Grid { height: 3, width: 2, data: vec![3, 6, 2, 5, 1, 4] });
+        assert_eq!(sut.rotate_cw().rotate_ccw(), sut);
+        assert_eq!(sut.rotate_cw().rotate_cw(), sut.flip_horizontal().flip_vertical());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(sut.transpose(), Grid { height: 3, width: 2, data: vec![1, 4, 2, 5, 3, 6] });
+        assert_eq!(sut.transpose().transpose(), sut);
+    }
+
+    #[test]
+    fn test_flip() {
+        let sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(sut.flip_horizontal(), Grid { height: 2, width: 3, data: vec![3, 2, 1, 6, 5, 4] });
+        assert_eq!(sut.flip_vertical(), Grid { height: 2, width: 3, data: vec![4, 5, 6, 1, 2, 3] });
+    }
+
+    #[test]
+    fn test_rows_columns() {
+        let sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(sut.row(1), &[4, 5, 6]);
+        assert_eq!(sut.rows().collect::<Vec<_>>(), vec![&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(sut.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        let columns: Vec<Vec<_>> = sut.columns().map(|x| x.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_outside() {
+        let sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let _ = sut.column(4);
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let diagonals: Vec<Vec<_>> = sut.diagonals().map(|x| x.copied().collect()).collect();
+        assert_eq!(diagonals, vec![vec![4], vec![1, 5], vec![2, 6], vec![3]]);
+        let diagonals: Vec<Vec<_>> = sut.anti_diagonals().map(|x| x.copied().collect()).collect();
+        assert_eq!(diagonals, vec![vec![1], vec![2, 4], vec![3, 5], vec![6]]);
+    }
+
+    #[test]
+    fn test_scale() {
+        let grid = CharGrid::from_str("#O\n@.").unwrap();
+        let sut = grid.scale(2, 1, |&x| match x {
+            b'O' => *b"[]",
+            b'@' => *b"@.",
+            x => [x, x],
+        });
+        assert_eq!(sut, CharGrid::from_str("##[]\n@..."));
+
+        let sut = grid.scale(2, 2, |&x| [x, b'.', b'.', x]).unwrap();
+        assert_eq!(sut, CharGrid::from_str("#.O.\n.#.O\n@...\n.@..").unwrap());
+
+        assert_eq!(grid.scale(2, 2, |&x| [x]), Err(Error::BlockDimension { width: 2, height: 2, len: 1 }));
+        assert_eq!(grid.scale(0, 2, |&x| [x]), Err(Error::BlockDimension { width: 0, height: 2, len: 0 }));
+    }
+
+    #[test]
+    fn test_pad_crop() {
+        let grid = Grid::with_data(2, &vec![1, 2, 3, 4]).unwrap();
+        let sut = grid.pad(1, 0);
+        assert_eq!(sut, Grid::with_data(4, &vec![0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4, 0, 0, 0, 0, 0]).unwrap());
+        assert_eq!(sut.crop(Area { max_x: 2, max_y: 2, min_x: 1, min_y: 1 }), Some(grid));
+        assert_eq!(sut.crop(Area { max_x: 4, max_y: 2, min_x: 1, min_y: 1 }), None);
+    }
+
+    #[test]
+    fn test_insert_remove_rows() {
+        let mut sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        sut.insert_row(1, 0);
+        assert_eq!(sut, Grid::with_data(3, &vec![1, 2, 3, 0, 0, 0, 4, 5, 6]).unwrap());
+        sut.insert_row(3, 9);
+        assert_eq!(sut.height, 4);
+        assert_eq!(sut.remove_row(3), vec![9, 9, 9]);
+        assert_eq!(sut.remove_row(0), vec![1, 2, 3]);
+        assert_eq!(sut, Grid::with_data(3, &vec![0, 0, 0, 4, 5, 6]).unwrap());
+    }
+
+    #[test]
+    fn test_insert_remove_columns() {
+        let mut sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        sut.insert_column(0, 0);
+        assert_eq!(sut, Grid::with_data(4, &vec![0, 1, 2, 3, 0, 4, 5, 6]).unwrap());
+        sut.insert_column(4, 9);
+        assert_eq!(sut, Grid::with_data(5, &vec![0, 1, 2, 3, 9, 0, 4, 5, 6, 9]).unwrap());
+        assert_eq!(sut.remove_column(2), vec![2, 5]);
+        assert_eq!(sut, Grid::with_data(4, &vec![0, 1, 3, 9, 0, 4, 6, 9]).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_row_outside() {
+        let mut sut = Grid::with_data(3, &vec![1, 2, 3, 4, 5, 6]).unwrap();
+        sut.remove_row(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_only_row() {
+        let mut sut = Grid::with_data(3, &vec![1, 2, 3]).unwrap();
+        sut.remove_row(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_only_column() {
+        let mut sut = Grid::with_data(1, &vec![1, 2, 3]).unwrap();
+        sut.remove_column(0);
+    }
+
+    #[test]
+    fn test_remove_from_line() {
+        let mut sut = Grid::with_data(3, &vec![1, 2, 3]).unwrap();
+        assert_eq!(sut.remove_column(1), vec![2]);
+        assert_eq!(sut, Grid::with_data(2, &vec![1, 3]).unwrap());
+
+        let mut sut = Grid::with_data(1, &vec![1, 2, 3]).unwrap();
+        assert_eq!(sut.remove_row(0), vec![1]);
+        assert_eq!(sut, Grid::with_data(1, &vec![2, 3]).unwrap());
+    }
+
+    #[test]
+    fn test_components() {
+        let sut = CharGrid::from_str("AAB\nCAB\nCCA").unwrap().components();
+        assert_eq!(sut.len(), 4);
+        assert_eq!(sut.labels.data, vec![0, 0, 1, 2, 0, 1, 2, 2, 3]);
+        assert_eq!(sut.sizes().collect::<Vec<_>>(), vec![3, 2, 3, 1]);
+        assert_eq!(sut.members[1], vec![Pos { x: 2, y: 0 }, Pos { x: 2, y: 1 }]);
+    }
+
+    #[test]
+    fn test_components_by() {
+        let grid = CharGrid::from_str("..#\n#.#\n.#.").unwrap();
+        let sut = grid.components_by(|&a, &b| a == b'.' && b == b'.');
+        assert_eq!(sut.len(), 7);
+        assert_eq!(sut.members[0], vec![Pos { x: 0, y: 0 }, Pos { x: 1, y: 0 }, Pos { x: 1, y: 1 }]);
+    }
 }
@@ -0,0 +1,218 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::ops::Index;
+use crate::geo::area::Area;
+use crate::geo::grid::Grid;
+use crate::geo::pos::{Pos, PosIdx};
+
+/// A borrowed view of a rectangular part of a [`Grid<T>`]. Positions are relative to the top left cell of the view.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    bounds: Area<usize>,
+}
+
+impl<T: Display> Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for (j, item) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", item)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<PosIdx> for GridView<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: PosIdx) -> &Self::Output {
+        assert!(self.has(&index), "{:?} is outside the view", index);
+        &self.grid[self.to_grid_pos(&index)]
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Returns the number of columns in this [`GridView<T>`].
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.bounds.cols()
+    }
+
+    /// Returns the number of rows in this [`GridView<T>`].
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.bounds.rows()
+    }
+
+    /// Returns the `Area<usize>` of the underlying [`Grid<T>`] covered by this [`GridView<T>`].
+    #[inline]
+    pub fn bounds(&self) -> Area<usize> {
+        self.bounds
+    }
+
+    /// Returns an `Area<usize>` which bounds this [`GridView<T>`], in view positions.
+    #[inline]
+    pub fn area(&self) -> Area<usize> {
+        Area { max_x: self.width() - 1, max_y: self.height() - 1, min_x: 0, min_y: 0 }
+    }
+
+    /// Returns whether this [`GridView<T>`] has a [`PosIdx`].
+    #[inline]
+    pub fn has(&self, pos: &PosIdx) -> bool {
+        pos.x < self.width() && pos.y < self.height()
+    }
+
+    /// Returns the size of this [`GridView<T>`].
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.bounds.size()
+    }
+
+    /// Returns the value at a [`PosIdx`], or [`None`] if it is outside this [`GridView<T>`].
+    #[inline]
+    pub fn get(&self, pos: &PosIdx) -> Option<&'a T> {
+        self.has(pos).then(|| &self.grid[self.to_grid_pos(pos)])
+    }
+
+    /// Returns the position in the underlying [`Grid<T>`] of a position in this [`GridView<T>`].
+    #[inline]
+    pub fn to_grid_pos(&self, pos: &PosIdx) -> PosIdx {
+        Pos { x: pos.x + self.bounds.min_x, y: pos.y + self.bounds.min_y }
+    }
+
+    /// Returns a row of this [`GridView<T>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y >= height`.
+    #[inline]
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height(), "row {} is outside the view", y);
+        let start = self.grid.width * (y + self.bounds.min_y) + self.bounds.min_x;
+        &self.grid.data[start..start + self.width()]
+    }
+
+    /// Returns an iterator over the rows of this [`GridView<T>`], from top to bottom.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height()).map(|y| self.row(y))
+    }
+
+    /// Returns an iterator over the values in this [`GridView<T>`], in reading order.
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.rows().flatten()
+    }
+
+    /// Returns a narrower [`GridView<T>`] over an `Area<usize>` of this view, or [`None`] if it does not fit.
+    pub fn view(&self, area: Area<usize>) -> Option<Self> {
+        if !self.has(&Pos { x: area.max_x, y: area.max_y }) {
+            return None;
+        }
+
+        let bounds = Area {
+            max_x: area.max_x + self.bounds.min_x,
+            max_y: area.max_y + self.bounds.min_y,
+            min_x: area.min_x + self.bounds.min_x,
+            min_y: area.min_y + self.bounds.min_y,
+        };
+        Some(Self { grid: self.grid, bounds })
+    }
+}
+
+impl<T: Clone> GridView<'_, T> {
+    /// Returns a new [`Grid<T>`] with a copy of the values in this [`GridView<T>`].
+    #[inline]
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid { width: self.width(), height: self.height(), data: self.values().cloned().collect::<Vec<_>>() }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns a [`GridView<T>`] over an `Area<usize>` of this [`Grid<T>`], or [`None`] if it does not fit.
+    #[inline]
+    pub fn view(&self, area: Area<usize>) -> Option<GridView<'_, T>> {
+        self.has(&Pos { x: area.max_x, y: area.max_y }).then_some(GridView { grid: self, bounds: area })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::format;
+    use core::str::FromStr;
+    use crate::geo::grid::CharGrid;
+    use super::*;
+
+    fn grid() -> Grid<u32> {
+        Grid::with_data(4, &(1..=12).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = grid();
+        let sut = grid.view(Area { max_x: 3, max_y: 2, min_x: 1, min_y: 1 }).unwrap();
+        assert_eq!(sut.width(), 3);
+        assert_eq!(sut.height(), 2);
+        assert_eq!(sut.size(), 6);
+        assert_eq!(sut[Pos { x: 0, y: 0 }], 6);
+        assert_eq!(sut[Pos { x: 2, y: 1 }], 12);
+        assert_eq!(sut.get(&Pos { x: 3, y: 0 }), None);
+        assert_eq!(sut.to_grid_pos(&Pos { x: 1, y: 1 }), Pos { x: 2, y: 2 });
+        assert_eq!(format!("{}", sut), "6 7 8\n10 11 12");
+
+        assert!(grid.view(Area { max_x: 4, max_y: 2, min_x: 1, min_y: 1 }).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_outside() {
+        let grid = grid();
+        let sut = grid.view(Area { max_x: 1, max_y: 1, min_x: 0, min_y: 0 }).unwrap();
+        let _ = sut[Pos { x: 2, y: 0 }];
+    }
+
+    #[test]
+    fn test_rows() {
+        let grid = grid();
+        let sut = grid.view(Area { max_x: 2, max_y: 2, min_x: 1, min_y: 0 }).unwrap();
+        assert_eq!(sut.row(1), &[6, 7]);
+        assert_eq!(sut.rows().collect::<Vec<_>>(), vec![&[2, 3], &[6, 7], &[10, 11]]);
+        assert_eq!(sut.values().copied().collect::<Vec<_>>(), vec![2, 3, 6, 7, 10, 11]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_row_outside() {
+        let grid = grid();
+        let sut = grid.view(Area { max_x: 2, max_y: 1, min_x: 1, min_y: 0 }).unwrap();
+        let _ = sut.row(2);
+    }
+
+    #[test]
+    fn test_nested_view() {
+        let grid = grid();
+        let sut = grid.view(Area { max_x: 3, max_y: 2, min_x: 1, min_y: 1 }).unwrap();
+        let inner = sut.view(Area { max_x: 2, max_y: 1, min_x: 1, min_y: 1 }).unwrap();
+        assert_eq!(inner.bounds(), Area { max_x: 3, max_y: 2, min_x: 2, min_y: 2 });
+        assert_eq!(inner.values().copied().collect::<Vec<_>>(), vec![11, 12]);
+        assert!(sut.view(Area { max_x: 3, max_y: 1, min_x: 0, min_y: 0 }).is_none());
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let grid = CharGrid::from_str("abc\ndef\nghi").unwrap();
+        let sut = grid.view(Area { max_x: 1, max_y: 2, min_x: 0, min_y: 1 }).unwrap();
+        assert_eq!(sut.to_grid(), CharGrid::from_str("de\ngh").unwrap());
+        assert_eq!(grid.view(grid.area()).unwrap().to_grid(), grid);
+    }
+}
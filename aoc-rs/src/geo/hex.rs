@@ -0,0 +1,411 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use num::Signed;
+use crate::error::{Error, Reason};
+
+/// The directions walked clockwise around a ring, starting from its [`HexDirection::SouthWest`] corner.
+const RING_WALK: [HexDirection; 6] = [
+    HexDirection::North,
+    HexDirection::NorthEast,
+    HexDirection::SouthEast,
+    HexDirection::South,
+    HexDirection::SouthWest,
+    HexDirection::NorthWest,
+];
+
+/// Represents the directions in a flat-topped hex grid.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl Display for HexDirection {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HexDirection::North => write!(f, "n"),
+            HexDirection::NorthEast => write!(f, "ne"),
+            HexDirection::SouthEast => write!(f, "se"),
+            HexDirection::South => write!(f, "s"),
+            HexDirection::SouthWest => write!(f, "sw"),
+            HexDirection::NorthWest => write!(f, "nw"),
+        }
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(HexDirection::North),
+            "ne" => Ok(HexDirection::NorthEast),
+            "se" => Ok(HexDirection::SouthEast),
+            "s" => Ok(HexDirection::South),
+            "sw" => Ok(HexDirection::SouthWest),
+            "nw" => Ok(HexDirection::NorthWest),
+            _ => Err(Error::parse(s, Reason::Invalid("hex direction"))),
+        }
+    }
+}
+
+impl HexDirection {
+    /// Returns all the directions, clockwise from [`HexDirection::North`].
+    #[inline]
+    pub fn all() -> [HexDirection; 6] {
+        [
+            HexDirection::North,
+            HexDirection::NorthEast,
+            HexDirection::SouthEast,
+            HexDirection::South,
+            HexDirection::SouthWest,
+            HexDirection::NorthWest,
+        ]
+    }
+
+    /// Returns the back [`HexDirection`] relative to the current [`HexDirection`].
+    #[inline]
+    pub fn back(&self) -> HexDirection {
+        self.rotate(3)
+    }
+
+    /// Returns the [`HexDirection`] 60 degrees counterclockwise of the current [`HexDirection`].
+    #[inline]
+    pub fn left(&self) -> HexDirection {
+        self.rotate(5)
+    }
+
+    /// Returns the [`HexDirection`] 60 degrees clockwise of the current [`HexDirection`].
+    #[inline]
+    pub fn right(&self) -> HexDirection {
+        self.rotate(1)
+    }
+
+    /// Returns the [`HexDirection`] rotated clockwise by a number of 60 degree turns.
+    #[inline]
+    pub fn rotate(&self, turns: usize) -> HexDirection {
+        Self::all()[(*self as usize + turns) % 6]
+    }
+
+    /// Parses a comma separated list of directions, such as `ne,sw,nw`.
+    pub fn parse_path(s: &str) -> Result<Vec<HexDirection>, Error> {
+        let mut column = 1;
+        s.trim()
+            .split(',')
+            .map(|item| {
+                let start = column;
+                column += item.len() + 1;
+                HexDirection::from_str(item.trim()).map_err(|e| e.at_column(start))
+            })
+            .collect()
+    }
+}
+
+/// A position in a flat-topped hex grid, in axial coordinates. The implied cube coordinate is `s = -q - r`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hex<T> {
+    pub q: T,
+    pub r: T,
+}
+
+impl<T: Display> Display for Hex<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Hex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { q: self.q + rhs.q, r: self.r + rhs.r }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Hex<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Hex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { q: self.q - rhs.q, r: self.r - rhs.r }
+    }
+}
+
+impl<T: SubAssign> SubAssign for Hex<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Hex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self { q: self.q * rhs, r: self.r * rhs }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Hex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { q: -self.q, r: -self.r }
+    }
+}
+
+impl<T: Signed> From<HexDirection> for Hex<T> {
+    #[inline]
+    fn from(value: HexDirection) -> Self {
+        match value {
+            HexDirection::North => Self { q: T::zero(), r: -T::one() },
+            HexDirection::NorthEast => Self { q: T::one(), r: -T::one() },
+            HexDirection::SouthEast => Self { q: T::one(), r: T::zero() },
+            HexDirection::South => Self { q: T::zero(), r: T::one() },
+            HexDirection::SouthWest => Self { q: -T::one(), r: T::one() },
+            HexDirection::NorthWest => Self { q: -T::one(), r: T::zero() },
+        }
+    }
+}
+
+impl<T> Hex<T> {
+    /// Returns a new [`Hex<T>`] from axial coordinates.
+    #[inline]
+    pub fn new(q: T, r: T) -> Self {
+        Self { q, r }
+    }
+}
+
+impl<T: Copy + Signed> Hex<T> {
+    /// Returns a new [`Hex<T>`] from cube coordinates, or [`None`] if they do not sum to zero.
+    #[inline]
+    pub fn from_cube(q: T, r: T, s: T) -> Option<Self> {
+        if (q + r + s).is_zero() { Some(Self { q, r }) } else { None }
+    }
+
+    /// Returns the [`Hex<T>`] at origin.
+    #[inline]
+    pub fn origin() -> Self {
+        Self { q: T::zero(), r: T::zero() }
+    }
+
+    /// Returns the third cube coordinate.
+    #[inline]
+    pub fn s(&self) -> T {
+        -self.q - self.r
+    }
+
+    /// Returns the cube coordinates `(q, r, s)`.
+    #[inline]
+    pub fn cube(&self) -> (T, T, T) {
+        (self.q, self.r, self.s())
+    }
+
+    /// Returns the neighbouring [`Hex<T>`] in a direction.
+    #[inline]
+    pub fn neighbour(&self, direction: HexDirection) -> Self {
+        *self + Self::from(direction)
+    }
+
+    /// Returns the destination [`Hex<T>`].
+    #[inline]
+    pub fn dest(&self, distance: T, direction: HexDirection) -> Self {
+        *self + Self::from(direction) * distance
+    }
+
+    /// Returns all 6 neighbouring [`Hex<T>`]s, clockwise from [`HexDirection::North`].
+    #[inline]
+    pub fn neighbours(&self) -> [Self; 6] {
+        HexDirection::all().map(|dir| self.neighbour(dir))
+    }
+
+    /// Returns the number of steps to another [`Hex<T>`].
+    #[inline]
+    pub fn distance(&self, other: &Self) -> T {
+        let diff = *self - *other;
+        (diff.q.abs() + diff.r.abs() + diff.s().abs()) / (T::one() + T::one())
+    }
+
+    /// Returns the [`Hex<T>`]s at exactly `radius` steps, starting from the south west corner and going clockwise.
+    #[inline]
+    pub fn ring(&self, radius: usize) -> HexRing<T> {
+        let mut current = *self;
+        for _ in 0..radius {
+            current = current.neighbour(HexDirection::SouthWest);
+        }
+        HexRing { current, radius, side: 0, step: 0 }
+    }
+
+    /// Returns the [`Hex<T>`]s within `radius` steps, ring by ring from this [`Hex<T>`] outwards.
+    #[inline]
+    pub fn spiral(&self, radius: usize) -> impl Iterator<Item = Self> + '_ {
+        (0..=radius).flat_map(|r| self.ring(r))
+    }
+}
+
+/// Represents an iterator over a ring of [`Hex<T>`]s. See [`Hex::ring`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct HexRing<T> {
+    current: Hex<T>,
+    radius: usize,
+    side: usize,
+    step: usize,
+}
+
+impl<T: Copy + Signed> Iterator for HexRing<T> {
+    type Item = Hex<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.side >= RING_WALK.len() {
+            return None;
+        }
+
+        let result = self.current;
+        if self.radius == 0 {
+            self.side = RING_WALK.len();
+            return Some(result);
+        }
+
+        self.current = self.current.neighbour(RING_WALK[self.side]);
+        self.step += 1;
+        if self.step == self.radius {
+            self.step = 0;
+            self.side += 1;
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::format;
+    use super::*;
+
+    #[test]
+    fn test_direction_from_str() {
+        assert_eq!(HexDirection::from_str("ne"), Ok(HexDirection::NorthEast));
+        assert_eq!(HexDirection::from_str("s"), Ok(HexDirection::South));
+        assert_eq!(HexDirection::from_str("e"), Err(Error::parse("e", Reason::Invalid("hex direction"))));
+        assert_eq!(format!("{}", HexDirection::NorthWest), "nw");
+    }
+
+    #[test]
+    fn test_parse_path() {
+        let sut = HexDirection::parse_path("ne,sw,nw\n");
+        assert_eq!(sut, Ok(vec![HexDirection::NorthEast, HexDirection::SouthWest, HexDirection::NorthWest]));
+
+        let sut = HexDirection::parse_path("n,x").unwrap_err();
+        assert_eq!(format!("{}", sut), "column 3: invalid hex direction in `x`");
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(HexDirection::North.right(), HexDirection::NorthEast);
+        assert_eq!(HexDirection::North.left(), HexDirection::NorthWest);
+        assert_eq!(HexDirection::NorthEast.back(), HexDirection::SouthWest);
+        assert_eq!(HexDirection::SouthWest.rotate(2), HexDirection::North);
+        assert_eq!(HexDirection::South.rotate(6), HexDirection::South);
+    }
+
+    #[test]
+    fn test_cube() {
+        let sut = Hex::new(2, -3);
+        assert_eq!(sut.s(), 1);
+        assert_eq!(sut.cube(), (2, -3, 1));
+        assert_eq!(Hex::from_cube(2, -3, 1), Some(sut));
+        assert_eq!(Hex::from_cube(2, -3, 0), None);
+    }
+
+    #[test]
+    fn test_ops() {
+        assert_eq!(Hex::new(1, 2) + Hex::new(3, -4), Hex::new(4, -2));
+        assert_eq!(Hex::new(1, 2) - Hex::new(3, -4), Hex::new(-2, 6));
+        assert_eq!(Hex::new(1, -2) * 3, Hex::new(3, -6));
+        assert_eq!(-Hex::new(1, -2), Hex::new(-1, 2));
+
+        let mut sut = Hex::new(1, 1);
+        sut += Hex::new(1, 1);
+        sut -= Hex::new(2, 0);
+        assert_eq!(sut, Hex::new(0, 2));
+        assert_eq!(format!("{}", sut), "(0, 2)");
+    }
+
+    #[test]
+    fn test_distance() {
+        let walk = |path: &str| {
+            HexDirection::parse_path(path)
+                .unwrap()
+                .into_iter()
+                .fold(Hex::<i32>::origin(), |hex, dir| hex.neighbour(dir))
+                .distance(&Hex::origin())
+        };
+        assert_eq!(walk("ne,ne,ne"), 3);
+        assert_eq!(walk("ne,ne,sw,sw"), 0);
+        assert_eq!(walk("ne,ne,s,s"), 2);
+        assert_eq!(walk("se,sw,se,sw,sw"), 3);
+        assert_eq!(Hex::new(0, 0).dest(4, HexDirection::SouthEast), Hex::new(4, 0));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let sut = Hex::new(0, 0).neighbours();
+        assert_eq!(sut[0], Hex::new(0, -1));
+        assert!(sut.iter().all(|x| x.distance(&Hex::origin()) == 1));
+    }
+
+    #[test]
+    fn test_ring() {
+        let center = Hex::new(1, -1);
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), vec![center]);
+
+        let sut: Vec<_> = center.ring(1).collect();
+        assert_eq!(sut.len(), 6);
+        assert_eq!(sut[0], Hex::new(0, 0));
+        assert!(center.neighbours().iter().all(|x| sut.contains(x)));
+
+        let sut: Vec<_> = Hex::origin().ring(1).collect();
+        let expected = [
+            HexDirection::SouthWest,
+            HexDirection::NorthWest,
+            HexDirection::North,
+            HexDirection::NorthEast,
+            HexDirection::SouthEast,
+            HexDirection::South,
+        ];
+        assert_eq!(sut, expected.map(Hex::<i32>::from));
+
+        let sut: Vec<_> = center.ring(3).collect();
+        assert_eq!(sut.len(), 18);
+        assert!(sut.iter().all(|x| x.distance(&center) == 3));
+    }
+
+    #[test]
+    fn test_spiral() {
+        let sut: Vec<_> = Hex::new(0, 0).spiral(2).collect();
+        assert_eq!(sut.len(), 19);
+        assert_eq!(sut[0], Hex::origin());
+        assert!(sut[1..7].iter().all(|x| x.distance(&Hex::origin()) == 1));
+    }
+}
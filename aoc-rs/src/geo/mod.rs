@@ -1,5 +1,14 @@
 pub mod area;
 pub mod area_iter;
+pub mod bit_grid;
 pub mod direction;
 pub mod grid;
+pub mod grid_view;
+pub mod hex;
 pub mod pos;
+pub mod pos3;
+pub mod region;
+pub mod render;
+pub mod sparse_grid;
+pub mod volume;
+pub mod volume_iter;
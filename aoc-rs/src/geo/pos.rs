@@ -186,6 +186,13 @@ impl<T: Copy + Num> Pos<T> {
         }
     }
 
+    /// Returns the destination [`Pos<T>`] in screen coordinates, where Y grows downward and [`Direction::Up`]
+    /// decreases Y.
+    #[inline]
+    pub fn screen_dest(&self, distance: T, direction: Direction) -> Self {
+        self.dest(distance, direction.flip_y())
+    }
+
     /// Returns the [`Pos<T>`] at origin.
     #[inline]
     pub fn origin() -> Self {
@@ -220,6 +227,13 @@ impl<T: Copy + Num + CheckedAdd + CheckedSub> Pos<T> {
             Direction::BottomRight => Some(Self { x: self.x.checked_add(&distance)?, y: self.y.checked_sub(&distance)? }),
         }
     }
+
+    /// Returns the destination [`Pos<T>`] in screen coordinates, or [`None`] if arithmetic error. See
+    /// [`Pos::screen_dest`].
+    #[inline]
+    pub fn checked_screen_dest(&self, distance: T, direction: Direction) -> Option<Self> {
+        self.checked_dest(distance, direction.flip_y())
+    }
 }
 
 impl<T: Copy + Signed> Pos<T> {
@@ -499,6 +513,25 @@ mod test {
         assert_eq!(sut.abs(), Pos { x: 1, y: 2 });
     }
 
+    #[test]
+    fn test_screen_dest() {
+        let sut = Pos { x: 0, y: 0 };
+        assert_eq!(sut.screen_dest(5, Direction::Up), Pos { x: 0, y: -5 });
+        assert_eq!(sut.screen_dest(5, Direction::Down), Pos { x: 0, y: 5 });
+        assert_eq!(sut.screen_dest(5, Direction::Left), Pos { x: -5, y: 0 });
+        assert_eq!(sut.screen_dest(5, Direction::TopRight), Pos { x: 5, y: -5 });
+        assert_eq!(sut.screen_dest(5, Direction::BottomLeft), Pos { x: -5, y: 5 });
+    }
+
+    #[test]
+    fn test_checked_screen_dest() {
+        let sut: Pos<usize> = Pos { x: 0, y: 0 };
+        assert_eq!(sut.checked_screen_dest(5, Direction::Up), None);
+        assert_eq!(sut.checked_screen_dest(5, Direction::Down), Some(Pos { x: 0, y: 5 }));
+        assert_eq!(sut.checked_screen_dest(5, Direction::BottomRight), Some(Pos { x: 5, y: 5 }));
+        assert_eq!(sut.checked_screen_dest(5, Direction::TopRight), None);
+    }
+
     #[test]
     fn test_checked_dest() {
         let sut: Pos<usize> = Pos { x: 0, y: 0 };
@@ -0,0 +1,377 @@
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use num::{CheckedAdd, CheckedSub, Num, Signed};
+use num::traits::{CheckedNeg, CheckedRem};
+
+/// The offsets to the 6 neighbours sharing a face.
+const FACES: [(i8, i8, i8); 6] = [(-1, 0, 0), (1, 0, 0), (0, -1, 0), (0, 1, 0), (0, 0, -1), (0, 0, 1)];
+
+/// A position in a 3D space.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Display> Display for Pos3<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+impl<T: AddAssign> AddAssign for Pos3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: Copy + CheckedAdd> CheckedAdd for Pos3<T> {
+    #[inline]
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(Self { x: self.x.checked_add(&v.x)?, y: self.y.checked_add(&v.y)?, z: self.z.checked_add(&v.z)? })
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl<T: SubAssign> SubAssign for Pos3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T: Copy + CheckedSub> CheckedSub for Pos3<T> {
+    #[inline]
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Some(Self { x: self.x.checked_sub(&v.x)?, y: self.y.checked_sub(&v.y)?, z: self.z.checked_sub(&v.z)? })
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+    }
+}
+
+impl<T: Copy + MulAssign> MulAssign<T> for Pos3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs }
+    }
+}
+
+impl<T: Copy + DivAssign> DivAssign<T> for Pos3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+impl<T: Rem<Output = T>> Rem for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self { x: self.x % rhs.x, y: self.y % rhs.y, z: self.z % rhs.z }
+    }
+}
+
+impl<T: RemAssign> RemAssign for Pos3<T> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        self.x %= rhs.x;
+        self.y %= rhs.y;
+        self.z %= rhs.z;
+    }
+}
+
+impl<T: CheckedRem> CheckedRem for Pos3<T> {
+    #[inline]
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        Some(Self { x: self.x.checked_rem(&v.x)?, y: self.y.checked_rem(&v.y)?, z: self.z.checked_rem(&v.z)? })
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl<T: CheckedNeg> CheckedNeg for Pos3<T> {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        Some(Self { x: self.x.checked_neg()?, y: self.y.checked_neg()?, z: self.z.checked_neg()? })
+    }
+}
+
+impl<T> Pos3<T> {
+    /// Returns a new [`Pos3<T>`].
+    #[inline]
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy + Num> Pos3<T> {
+    /// Computes modulo between this [`Pos3<T>`] and another [`Pos3<T>`].
+    #[inline]
+    pub fn modulo(&self, rhs: &Self) -> Self {
+        Self {
+            x: (self.x % rhs.x + rhs.x) % rhs.x,
+            y: (self.y % rhs.y + rhs.y) % rhs.y,
+            z: (self.z % rhs.z + rhs.z) % rhs.z,
+        }
+    }
+
+    /// Returns the [`Pos3<T>`] at origin.
+    #[inline]
+    pub fn origin() -> Self {
+        Self { x: T::zero(), y: T::zero(), z: T::zero() }
+    }
+
+    /// Returns the [`Pos3<T>`] at unit X.
+    #[inline]
+    pub fn unit_x() -> Self {
+        Self { x: T::one(), y: T::zero(), z: T::zero() }
+    }
+
+    /// Returns the [`Pos3<T>`] at unit Y.
+    #[inline]
+    pub fn unit_y() -> Self {
+        Self { x: T::zero(), y: T::one(), z: T::zero() }
+    }
+
+    /// Returns the [`Pos3<T>`] at unit Z.
+    #[inline]
+    pub fn unit_z() -> Self {
+        Self { x: T::zero(), y: T::zero(), z: T::one() }
+    }
+}
+
+impl<T: Copy + Num + CheckedAdd + CheckedSub> Pos3<T> {
+    /// Returns the 6 neighbouring [`Pos3<T>`]s sharing a face. Neighbours causing arithmetic error are skipped.
+    #[inline]
+    pub fn face_neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        FACES.into_iter().filter_map(|offset| self.checked_offset(offset))
+    }
+
+    /// Returns the 26 neighbouring [`Pos3<T>`]s sharing a face, an edge or a corner. Neighbours causing arithmetic
+    /// error are skipped.
+    #[inline]
+    pub fn all_neighbours(&self) -> impl Iterator<Item = Self> + '_ {
+        (-1..=1)
+            .flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| (x, y, z))))
+            .filter(|&offset| offset != (0, 0, 0))
+            .filter_map(|offset| self.checked_offset(offset))
+    }
+
+    fn checked_offset(&self, (x, y, z): (i8, i8, i8)) -> Option<Self> {
+        let step = |value: T, offset: i8| match offset {
+            -1 => value.checked_sub(&T::one()),
+            1 => value.checked_add(&T::one()),
+            _ => Some(value),
+        };
+        Some(Self { x: step(self.x, x)?, y: step(self.y, y)?, z: step(self.z, z)? })
+    }
+}
+
+impl<T: Copy + Signed> Pos3<T> {
+    /// Returns the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry).
+    #[inline]
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Returns a [`Pos3<T>`] with X, Y and Z's abs value.
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self { x: self.x.abs(), y: self.y.abs(), z: self.z.abs() }
+    }
+}
+
+impl<T: Copy> Pos3<T> {
+    /// Returns a [`Pos3<T>`] with X, Y and Z set to the same value.
+    #[inline]
+    pub fn with_same(xyz: T) -> Self {
+        Self { x: xyz, y: xyz, z: xyz }
+    }
+}
+
+pub type Pos3Idx = Pos3<usize>;
+
+impl Pos3Idx {
+    /// Returns the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry).
+    #[inline]
+    pub fn manhattan_unsigned(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::format;
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let sut = Pos3 { x: 1, y: -2, z: 3 };
+        assert_eq!(format!("{}", sut), "(1, -2, 3)");
+    }
+
+    #[test]
+    fn test_add() {
+        let sut = Pos3 { x: 1, y: 2, z: 3 } + Pos3 { x: 4, y: 5, z: 6 };
+        assert_eq!(sut, Pos3 { x: 5, y: 7, z: 9 });
+
+        let mut sut = Pos3 { x: 1, y: 2, z: 3 };
+        sut += Pos3 { x: -1, y: -2, z: -3 };
+        assert_eq!(sut, Pos3::origin());
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let sut: Pos3<u8> = Pos3 { x: 1, y: 2, z: 3 };
+        assert_eq!(sut.checked_add(&Pos3 { x: 1, y: 1, z: 1 }), Some(Pos3 { x: 2, y: 3, z: 4 }));
+        assert_eq!(sut.checked_add(&Pos3 { x: 0, y: 0, z: 255 }), None);
+    }
+
+    #[test]
+    fn test_sub() {
+        let sut = Pos3 { x: 1, y: 2, z: 3 } - Pos3 { x: 4, y: 5, z: 6 };
+        assert_eq!(sut, Pos3 { x: -3, y: -3, z: -3 });
+
+        let mut sut = Pos3 { x: 1, y: 2, z: 3 };
+        sut -= Pos3 { x: 1, y: 2, z: 3 };
+        assert_eq!(sut, Pos3::origin());
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let sut: Pos3<usize> = Pos3 { x: 1, y: 2, z: 3 };
+        assert_eq!(sut.checked_sub(&Pos3 { x: 1, y: 1, z: 1 }), Some(Pos3 { x: 0, y: 1, z: 2 }));
+        assert_eq!(sut.checked_sub(&Pos3 { x: 0, y: 3, z: 0 }), None);
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let mut sut = Pos3 { x: 1, y: -2, z: 3 } * 2;
+        assert_eq!(sut, Pos3 { x: 2, y: -4, z: 6 });
+        sut /= 2;
+        assert_eq!(sut, Pos3 { x: 1, y: -2, z: 3 });
+        sut *= 3;
+        assert_eq!(sut / 3, Pos3 { x: 1, y: -2, z: 3 });
+    }
+
+    #[test]
+    fn test_rem() {
+        let mut sut = Pos3 { x: 5, y: 7, z: 9 } % Pos3 { x: 2, y: 3, z: 4 };
+        assert_eq!(sut, Pos3 { x: 1, y: 1, z: 1 });
+        sut %= Pos3 { x: 1, y: 1, z: 1 };
+        assert_eq!(sut, Pos3::origin());
+        assert_eq!(sut.checked_rem(&Pos3 { x: 1, y: 0, z: 1 }), None);
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(-Pos3 { x: 1, y: -2, z: 3 }, Pos3 { x: -1, y: 2, z: -3 });
+        assert_eq!(Pos3 { x: 1, y: -2, z: 3 }.checked_neg(), Some(Pos3 { x: -1, y: 2, z: -3 }));
+        assert_eq!(Pos3 { x: i8::MIN, y: 0, z: 0 }.checked_neg(), None);
+    }
+
+    #[test]
+    fn test_modulo() {
+        let sut = Pos3 { x: -1, y: 5, z: -7 }.modulo(&Pos3 { x: 3, y: 3, z: 3 });
+        assert_eq!(sut, Pos3 { x: 2, y: 2, z: 2 });
+    }
+
+    #[test]
+    fn test_units() {
+        assert_eq!(Pos3::<i32>::unit_x(), Pos3 { x: 1, y: 0, z: 0 });
+        assert_eq!(Pos3::<i32>::unit_y(), Pos3 { x: 0, y: 1, z: 0 });
+        assert_eq!(Pos3::<i32>::unit_z(), Pos3 { x: 0, y: 0, z: 1 });
+        assert_eq!(Pos3::with_same(4), Pos3 { x: 4, y: 4, z: 4 });
+        assert_eq!(Pos3::new(1, 2, 3), Pos3 { x: 1, y: 2, z: 3 });
+    }
+
+    #[test]
+    fn test_face_neighbours() {
+        let sut: Vec<_> = Pos3 { x: 0, y: 0, z: 0 }.face_neighbours().collect();
+        assert_eq!(sut, vec![
+            Pos3 { x: -1, y: 0, z: 0 },
+            Pos3 { x: 1, y: 0, z: 0 },
+            Pos3 { x: 0, y: -1, z: 0 },
+            Pos3 { x: 0, y: 1, z: 0 },
+            Pos3 { x: 0, y: 0, z: -1 },
+            Pos3 { x: 0, y: 0, z: 1 },
+        ]);
+
+        let sut: Vec<_> = Pos3Idx { x: 0, y: 1, z: 0 }.face_neighbours().collect();
+        assert_eq!(sut.len(), 4);
+    }
+
+    #[test]
+    fn test_all_neighbours() {
+        let sut: Vec<_> = Pos3 { x: 5, y: 5, z: 5 }.all_neighbours().collect();
+        assert_eq!(sut.len(), 26);
+        assert_eq!(sut[0], Pos3 { x: 4, y: 4, z: 4 });
+        assert_eq!(sut[25], Pos3 { x: 6, y: 6, z: 6 });
+        assert!(!sut.contains(&Pos3 { x: 5, y: 5, z: 5 }));
+
+        let sut: Vec<_> = Pos3Idx { x: 0, y: 0, z: 0 }.all_neighbours().collect();
+        assert_eq!(sut.len(), 7);
+    }
+
+    #[test]
+    fn test_manhattan() {
+        let sut = Pos3 { x: 1, y: -2, z: 3 };
+        assert_eq!(sut.manhattan(&Pos3 { x: -1, y: 2, z: 0 }), 9);
+        assert_eq!(sut.abs(), Pos3 { x: 1, y: 2, z: 3 });
+
+        let sut = Pos3Idx { x: 1, y: 5, z: 3 };
+        assert_eq!(sut.manhattan_unsigned(&Pos3 { x: 4, y: 2, z: 3 }), 6);
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use crate::geo::area::Area;
+use crate::geo::direction::Direction;
+use crate::geo::grid::Grid;
+use crate::geo::pos::{Pos, PosIdx};
+
+/// The orthogonal directions on either side of each diagonal direction.
+const CORNERS: [(Direction, Direction, Direction); 4] = [
+    (Direction::Up, Direction::Left, Direction::TopLeft),
+    (Direction::Up, Direction::Right, Direction::TopRight),
+    (Direction::Down, Direction::Left, Direction::BottomLeft),
+    (Direction::Down, Direction::Right, Direction::BottomRight),
+];
+
+/// A connected region of cells in a [`Grid<T>`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Region {
+    cells: Vec<PosIdx>,
+    bounds: Area<usize>,
+    mask: Grid<bool>,
+}
+
+impl Region {
+    /// Returns a new [`Region`] from a non-empty list of cells.
+    pub(crate) fn new(cells: Vec<PosIdx>) -> Self {
+        let bounds = Area {
+            max_x: cells.iter().map(|p| p.x).max().unwrap_or(0),
+            max_y: cells.iter().map(|p| p.y).max().unwrap_or(0),
+            min_x: cells.iter().map(|p| p.x).min().unwrap_or(0),
+            min_y: cells.iter().map(|p| p.y).min().unwrap_or(0),
+        };
+        let mut mask = Grid { width: bounds.cols(), height: bounds.rows(), data: vec![false; bounds.size()] };
+        for pos in &cells {
+            mask[Pos { x: pos.x - bounds.min_x, y: pos.y - bounds.min_y }] = true;
+        }
+        Self { cells, bounds, mask }
+    }
+
+    /// Returns the cells in this [`Region`].
+    #[inline]
+    pub fn cells(&self) -> &[PosIdx] {
+        &self.cells
+    }
+
+    /// Returns the number of cells in this [`Region`].
+    #[inline]
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the smallest `Area<usize>` which bounds this [`Region`].
+    #[inline]
+    pub fn bounds(&self) -> Area<usize> {
+        self.bounds
+    }
+
+    /// Returns whether a [`PosIdx`] is in this [`Region`].
+    #[inline]
+    pub fn contains(&self, pos: &PosIdx) -> bool {
+        self.bounds.has(pos) && self.mask[Pos { x: pos.x - self.bounds.min_x, y: pos.y - self.bounds.min_y }]
+    }
+
+    /// Returns the length of the boundary, counting each cell edge which does not touch another cell.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|pos| Direction::cross().into_iter().filter(|&dir| !self.has_neighbour(pos, dir)).count())
+            .sum()
+    }
+
+    /// Returns the number of straight sides, which equals the number of corners.
+    pub fn sides(&self) -> usize {
+        self.cells.iter().map(|pos| self.corners(pos)).sum()
+    }
+
+    /// Returns the regions of cells enclosed by this [`Region`].
+    pub fn holes(&self) -> Vec<Region> {
+        let outside = self.mask.components_by(|a, b| !a && !b);
+        let area = self.mask.area();
+        outside
+            .members
+            .into_iter()
+            .filter(|cells| !self.mask[cells[0]] && !cells.iter().any(|pos| area.on_boundary(pos)))
+            .map(|cells| {
+                let cells = cells
+                    .into_iter()
+                    .map(|pos| Pos { x: pos.x + self.bounds.min_x, y: pos.y + self.bounds.min_y })
+                    .collect();
+                Region::new(cells)
+            })
+            .collect()
+    }
+
+    /// Returns the number of corners a cell contributes to the boundary.
+    fn corners(&self, pos: &PosIdx) -> usize {
+        CORNERS
+            .iter()
+            .filter(|&&(a, b, diagonal)| {
+                match (self.has_neighbour(pos, a), self.has_neighbour(pos, b)) {
+                    (false, false) => true,
+                    (true, true) => !self.has_neighbour(pos, diagonal),
+                    _ => false,
+                }
+            })
+            .count()
+    }
+
+    #[inline]
+    fn has_neighbour(&self, pos: &PosIdx, direction: Direction) -> bool {
+        pos.checked_dest(1, direction).is_some_and(|pos| self.contains(&pos))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns the regions of neighbouring cells with the same value.
+    #[inline]
+    pub fn regions(&self) -> Vec<Region> {
+        self.components().members.into_iter().map(Region::new).collect()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the regions of cells, where neighbouring cells connect if `connected` returns true.
+    #[inline]
+    pub fn regions_by(&self, connected: impl FnMut(&T, &T) -> bool) -> Vec<Region> {
+        self.components_by(connected).members.into_iter().map(Region::new).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+    use crate::geo::grid::CharGrid;
+    use super::*;
+
+    #[test]
+    fn test_regions() {
+        let sut = CharGrid::from_str("AAAA\nBBCD\nBBCC\nEEEC").unwrap().regions();
+        let summary: Vec<_> = sut.iter().map(|x| (x.area(), x.perimeter(), x.sides())).collect();
+        assert_eq!(summary, vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let sut = CharGrid::from_str("AAB\nBAB\nBAA").unwrap().regions();
+        assert_eq!(sut[0].bounds(), Area { max_x: 2, max_y: 2, min_x: 0, min_y: 0 });
+        assert_eq!(sut[1].bounds(), Area { max_x: 2, max_y: 1, min_x: 2, min_y: 0 });
+        assert!(sut[0].contains(&Pos { x: 1, y: 1 }));
+        assert!(!sut[0].contains(&Pos { x: 0, y: 1 }));
+        assert!(!sut[0].contains(&Pos { x: 5, y: 5 }));
+    }
+
+    #[test]
+    fn test_holes() {
+        let grid = CharGrid::from_str("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO").unwrap();
+        let sut = &grid.regions()[0];
+        assert_eq!(sut.area(), 21);
+        assert_eq!(sut.perimeter(), 36);
+        assert_eq!(sut.sides(), 20);
+
+        let holes = sut.holes();
+        assert_eq!(holes.len(), 4);
+        assert_eq!(holes[0].cells(), &[Pos { x: 1, y: 1 }]);
+        assert_eq!(holes[3].cells(), &[Pos { x: 3, y: 3 }]);
+        assert!(grid.regions()[1].holes().is_empty());
+    }
+
+    #[test]
+    fn test_regions_by() {
+        let grid = CharGrid::from_str("#..\n#.#\n..#").unwrap();
+        let sut = grid.regions_by(|&a, &b| a == b'.' && b == b'.');
+        assert_eq!(sut.len(), 5);
+        assert_eq!(sut[1].area(), 5);
+        assert_eq!(sut[1].sides(), 8);
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use crate::geo::grid::{CharGrid, Grid};
+use crate::geo::pos::PosIdx;
+
+/// An ANSI terminal foreground color.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Returns the ANSI SGR code of this [`Color`].
+    #[inline]
+    pub fn code(&self) -> u8 {
+        match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+        }
+    }
+}
+
+/// The direction in which Y increases when rendering.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    /// Row 0 is printed first, matching screen coordinates.
+    #[default]
+    YDown,
+    /// Row 0 is printed last, matching math coordinates.
+    YUp,
+}
+
+/// A set of positions drawn over a grid with a glyph.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Overlay {
+    positions: Vec<PosIdx>,
+    glyph: char,
+    color: Option<Color>,
+}
+
+/// Renders a [`CharGrid`] as text, one character per cell, with optional overlays of marked positions.
+#[derive(Clone, Debug)]
+pub struct Render<'a> {
+    grid: &'a CharGrid,
+    overlays: Vec<Overlay>,
+    ansi: bool,
+    orientation: Orientation,
+}
+
+impl Display for Render<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut layers: Grid<Option<usize>> =
+            Grid { width: self.grid.width, height: self.grid.height, data: vec![None; self.grid.size()] };
+        for (i, overlay) in self.overlays.iter().enumerate() {
+            for pos in overlay.positions.iter().filter(|pos| self.grid.has(pos)) {
+                layers[*pos] = Some(i);
+            }
+        }
+
+        for i in 0..self.grid.height {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            let y = match self.orientation {
+                Orientation::YDown => i,
+                Orientation::YUp => self.grid.height - 1 - i,
+            };
+            for (x, &cell) in self.grid.row(y).iter().enumerate() {
+                match layers[PosIdx::new(x, y)].map(|layer| &self.overlays[layer]) {
+                    Some(Overlay { glyph, color: Some(color), .. }) if self.ansi => {
+                        write!(f, "\x1b[{}m{}\x1b[0m", color.code(), glyph)?
+                    },
+                    Some(overlay) => write!(f, "{}", overlay.glyph)?,
+                    None => write!(f, "{}", cell as char)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Render<'a> {
+    /// Returns a new [`Render`] of a [`CharGrid`] with no overlays, no colors and Y pointing down.
+    #[inline]
+    pub fn new(grid: &'a CharGrid) -> Self {
+        Self { grid, overlays: Vec::new(), ansi: false, orientation: Orientation::YDown }
+    }
+
+    /// Draws a glyph at each position. Later overlays are drawn over earlier ones, and positions outside the grid are
+    /// ignored.
+    #[inline]
+    pub fn overlay(mut self, positions: impl IntoIterator<Item = PosIdx>, glyph: char) -> Self {
+        self.overlays.push(Overlay { positions: positions.into_iter().collect(), glyph, color: None });
+        self
+    }
+
+    /// Draws a glyph at each position, in a color when ANSI output is enabled.
+    #[inline]
+    pub fn overlay_color(mut self, positions: impl IntoIterator<Item = PosIdx>, glyph: char, color: Color) -> Self {
+        self.overlays.push(Overlay { positions: positions.into_iter().collect(), glyph, color: Some(color) });
+        self
+    }
+
+    /// Enables or disables ANSI color escape codes.
+    #[inline]
+    pub fn ansi(mut self, ansi: bool) -> Self {
+        self.ansi = ansi;
+        self
+    }
+
+    /// Sets the direction in which Y increases.
+    #[inline]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+}
+
+impl CharGrid {
+    /// Returns a [`Render`] which prints this [`CharGrid`] as characters.
+    #[inline]
+    pub fn render(&self) -> Render<'_> {
+        Render::new(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::format;
+    use core::str::FromStr;
+    use crate::geo::pos::Pos;
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let grid = CharGrid::from_str("#..\n.#.").unwrap();
+        assert_eq!(format!("{}", grid.render()), "#..\n.#.");
+        assert_eq!(format!("{}", grid.render().orientation(Orientation::YUp)), ".#.\n#..");
+    }
+
+    #[test]
+    fn test_overlay() {
+        let grid = CharGrid::from_str("#..\n.#.").unwrap();
+        let sut = grid
+            .render()
+            .overlay([Pos { x: 1, y: 0 }, Pos { x: 2, y: 0 }, Pos { x: 9, y: 9 }], 'o')
+            .overlay([Pos { x: 2, y: 0 }], '@');
+        assert_eq!(format!("{}", sut), "#o@\n.#.");
+    }
+
+    #[test]
+    fn test_ansi() {
+        let grid = CharGrid::from_str("..").unwrap();
+        let sut = grid.render().overlay_color([Pos { x: 1, y: 0 }], '*', Color::Red);
+        assert_eq!(format!("{}", sut), ".*");
+        assert_eq!(format!("{}", sut.ansi(true)), ".\x1b[31m*\x1b[0m");
+    }
+}
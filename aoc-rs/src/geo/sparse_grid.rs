@@ -0,0 +1,221 @@
+#![cfg(feature = "std")]
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use crate::geo::area::Area;
+use crate::geo::direction::Direction;
+use crate::geo::grid::Grid;
+use crate::geo::pos::Pos;
+
+/// Represents an unbounded 2D grid which only stores occupied cells. Unoccupied cells have a default value.
+///
+/// Like [`Grid<T>`], it uses screen coordinates: [`Direction::Up`] decreases Y.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos<i64>, T>,
+    default: T,
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    /// Renders the bounding box of the occupied cells, in the same format as [`Grid<T>`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in bounds.min_y..=bounds.max_y {
+            if y > bounds.min_y {
+                writeln!(f)?;
+            }
+
+            for x in bounds.min_x..=bounds.max_x {
+                if x > bounds.min_x {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", self.get(&Pos { x, y }))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Returns a new empty [`SparseGrid<T>`] with a default value.
+    #[inline]
+    pub fn new(default: T) -> Self {
+        Self { cells: HashMap::new(), default }
+    }
+
+    /// Returns the value at a [`Pos<i64>`], or the default value if it is unoccupied.
+    #[inline]
+    pub fn get(&self, pos: &Pos<i64>) -> &T {
+        self.cells.get(pos).unwrap_or(&self.default)
+    }
+
+    /// Sets the value at a [`Pos<i64>`], returning the previous value if it was occupied.
+    #[inline]
+    pub fn insert(&mut self, pos: Pos<i64>, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    /// Clears a [`Pos<i64>`] back to the default value, returning the previous value if it was occupied.
+    #[inline]
+    pub fn remove(&mut self, pos: &Pos<i64>) -> Option<T> {
+        self.cells.remove(pos)
+    }
+
+    /// Returns whether a [`Pos<i64>`] is occupied.
+    #[inline]
+    pub fn contains(&self, pos: &Pos<i64>) -> bool {
+        self.cells.contains_key(pos)
+    }
+
+    /// Returns the number of occupied cells.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if no cells are occupied.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the default value.
+    #[inline]
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Returns an iterator over the occupied cells, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&Pos<i64>, &T)> {
+        self.cells.iter()
+    }
+
+    /// Returns the smallest `Area<i64>` which bounds the occupied cells, or [`None`] if there are none.
+    pub fn bounds(&self) -> Option<Area<i64>> {
+        let mut positions = self.cells.keys();
+        let first = positions.next()?;
+        let mut result = Area { max_x: first.x, max_y: first.y, min_x: first.x, min_y: first.y };
+        for pos in positions {
+            result.max_x = result.max_x.max(pos.x);
+            result.max_y = result.max_y.max(pos.y);
+            result.min_x = result.min_x.min(pos.x);
+            result.min_y = result.min_y.min(pos.y);
+        }
+        Some(result)
+    }
+
+    /// Returns the neighbouring [`Pos<i64>`]s of a [`Pos<i64>`] and their values.
+    #[inline]
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: &'a Pos<i64>,
+        directions: impl IntoIterator<Item = Direction> + 'a
+    ) -> impl Iterator<Item = (Pos<i64>, &'a T)> + 'a {
+        directions.into_iter().map(move |dir| {
+            let pos = pos.screen_dest(1, dir);
+            (pos, self.get(&pos))
+        })
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Returns a mutable reference to the value at a [`Pos<i64>`], occupying it with the default value if needed.
+    #[inline]
+    pub fn get_mut(&mut self, pos: Pos<i64>) -> &mut T {
+        self.cells.entry(pos).or_insert_with(|| self.default.clone())
+    }
+
+    /// Returns a [`Grid<T>`] of the bounding box of the occupied cells, or [`None`] if there are none. The top left
+    /// cell of the bounding box becomes the origin of the [`Grid<T>`].
+    pub fn to_grid(&self) -> Option<Grid<T>> {
+        let bounds = self.bounds()?;
+        let data = bounds.into_iter().map(|pos| self.get(&pos).clone()).collect();
+        Some(Grid { width: bounds.cols() as usize, height: bounds.rows() as usize, data })
+    }
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Returns a new [`SparseGrid<T>`] from a [`Grid<T>`], occupying the cells which differ from the default value.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self {
+        let cells = grid
+            .area()
+            .into_iter()
+            .filter(|&pos| grid[pos] != default)
+            .map(|pos| (Pos { x: pos.x as i64, y: pos.y as i64 }, grid[pos].clone()))
+            .collect();
+        Self { cells, default }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use crate::geo::grid::CharGrid;
+    use super::*;
+
+    #[test]
+    fn test_get_insert() {
+        let mut sut = SparseGrid::new(0);
+        assert!(sut.is_empty());
+        assert_eq!(sut.insert(Pos { x: -5, y: 3 }, 7), None);
+        assert_eq!(sut.insert(Pos { x: -5, y: 3 }, 8), Some(7));
+        assert_eq!(*sut.get(&Pos { x: -5, y: 3 }), 8);
+        assert_eq!(*sut.get(&Pos { x: 100, y: 100 }), 0);
+        assert!(sut.contains(&Pos { x: -5, y: 3 }));
+        assert_eq!(sut.len(), 1);
+
+        *sut.get_mut(Pos { x: 1, y: 1 }) += 2;
+        assert_eq!(*sut.get(&Pos { x: 1, y: 1 }), 2);
+        assert_eq!(sut.remove(&Pos { x: 1, y: 1 }), Some(2));
+        assert!(!sut.contains(&Pos { x: 1, y: 1 }));
+        assert_eq!(*sut.default_value(), 0);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut sut = SparseGrid::new(false);
+        assert_eq!(sut.bounds(), None);
+
+        sut.insert(Pos { x: -2, y: 5 }, true);
+        sut.insert(Pos { x: 3, y: -1 }, true);
+        assert_eq!(sut.bounds(), Some(Area { max_x: 3, max_y: 5, min_x: -2, min_y: -1 }));
+        assert_eq!(sut.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let mut sut = SparseGrid::new('.');
+        sut.insert(Pos { x: 0, y: -1 }, '#');
+        let neighbours: Vec<_> = sut.neighbours(&Pos { x: 0, y: 0 }, [Direction::Up, Direction::Down]).collect();
+        assert_eq!(neighbours, vec![(Pos { x: 0, y: -1 }, &'#'), (Pos { x: 0, y: 1 }, &'.')]);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut sut = SparseGrid::new(0);
+        assert_eq!(format!("{}", sut), "");
+
+        sut.insert(Pos { x: -1, y: -1 }, 1);
+        sut.insert(Pos { x: 1, y: 0 }, 2);
+        assert_eq!(format!("{}", sut), "1 0 0\n0 0 2");
+    }
+
+    #[test]
+    fn test_grid_conversion() {
+        let grid = CharGrid::from_str("..#\n#..").unwrap();
+        let sut = SparseGrid::from_grid(&grid, b'.');
+        assert_eq!(sut.len(), 2);
+        assert_eq!(*sut.get(&Pos { x: 2, y: 0 }), b'#');
+        assert_eq!(sut.to_grid(), Some(grid));
+
+        let mut sut = SparseGrid::new(b'.');
+        sut.insert(Pos { x: -3, y: 4 }, b'#');
+        sut.insert(Pos { x: -2, y: 5 }, b'#');
+        assert_eq!(sut.to_grid(), Some(CharGrid::from_str("#.\n.#").unwrap()));
+        assert_eq!(SparseGrid::new(b'.').to_grid(), None);
+    }
+}
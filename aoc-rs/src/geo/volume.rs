@@ -0,0 +1,227 @@
+use num::{CheckedAdd, CheckedSub, Num};
+use crate::error::Error;
+use crate::geo::pos3::Pos3;
+use crate::geo::volume_iter::VolumeIterator;
+
+/// A 3D volume.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Volume<T> {
+    pub max_x: T,
+    pub max_y: T,
+    pub max_z: T,
+    pub min_x: T,
+    pub min_y: T,
+    pub min_z: T,
+}
+
+impl<T: Copy + Num + PartialOrd> IntoIterator for Volume<T> {
+    type Item = Pos3<T>;
+    type IntoIter = VolumeIterator<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Self::IntoIter { volume: self, current_x: self.min_x, current_y: self.min_y, current_z: self.min_z }
+    }
+}
+
+impl<T: PartialOrd> Volume<T> {
+    /// Returns a new [`Volume<T>`].
+    pub fn new(max_x: T, max_y: T, max_z: T, min_x: T, min_y: T, min_z: T) -> Result<Self, Error> {
+        if max_x < min_x || max_y < min_y || max_z < min_z {
+            return Err(Error::VolumeBoundary);
+        }
+        Ok(Self { max_x, max_y, max_z, min_x, min_y, min_z })
+    }
+
+    /// Checks whether a [`Pos3<T>`] is in this [`Volume<T>`].
+    #[inline]
+    pub fn has(&self, pos: &Pos3<T>) -> bool {
+        pos.x >= self.min_x && pos.x <= self.max_x
+            && pos.y >= self.min_y && pos.y <= self.max_y
+            && pos.z >= self.min_z && pos.z <= self.max_z
+    }
+
+    /// Filters a list of [`Pos3<T>`] to the ones inside this [`Volume<T>`].
+    #[inline]
+    pub fn filter_pos<'a>(&'a self, pos: impl IntoIterator<Item = Pos3<T>> + 'a) -> impl Iterator<Item = Pos3<T>> + 'a {
+        pos.into_iter().filter(move |p| self.has(p))
+    }
+}
+
+impl<T: Copy + PartialOrd> Volume<T> {
+    /// Returns a [`Volume<T>`] constructed from its minimum and maximum [`Pos3<T>`]s.
+    pub fn from_pos(min: &Pos3<T>, max: &Pos3<T>) -> Result<Self, Error> {
+        Self::new(max.x, max.y, max.z, min.x, min.y, min.z)
+    }
+
+    /// Returns the smallest [`Volume<T>`] containing all the [`Pos3<T>`]s, or [`None`] if there are none.
+    pub fn bounding(pos: impl IntoIterator<Item = Pos3<T>>) -> Option<Self> {
+        let mut iter = pos.into_iter();
+        let first = iter.next()?;
+        let mut result = Self::from_pos(&first, &first).ok()?;
+        for p in iter {
+            if p.x < result.min_x { result.min_x = p.x; }
+            if p.x > result.max_x { result.max_x = p.x; }
+            if p.y < result.min_y { result.min_y = p.y; }
+            if p.y > result.max_y { result.max_y = p.y; }
+            if p.z < result.min_z { result.min_z = p.z; }
+            if p.z > result.max_z { result.max_z = p.z; }
+        }
+        Some(result)
+    }
+}
+
+impl<T: Copy> Volume<T> {
+    /// Returns the minimum [`Pos3<T>`].
+    #[inline]
+    pub fn min(&self) -> Pos3<T> {
+        Pos3 { x: self.min_x, y: self.min_y, z: self.min_z }
+    }
+
+    /// Returns the maximum [`Pos3<T>`].
+    #[inline]
+    pub fn max(&self) -> Pos3<T> {
+        Pos3 { x: self.max_x, y: self.max_y, z: self.max_z }
+    }
+}
+
+impl<T: Copy + Num> Volume<T> {
+    /// Returns the X length.
+    #[inline]
+    pub fn width(&self) -> T {
+        self.max_x - self.min_x + T::one()
+    }
+
+    /// Returns the Y length.
+    #[inline]
+    pub fn height(&self) -> T {
+        self.max_y - self.min_y + T::one()
+    }
+
+    /// Returns the Z length.
+    #[inline]
+    pub fn depth(&self) -> T {
+        self.max_z - self.min_z + T::one()
+    }
+
+    /// Returns the volume size.
+    #[inline]
+    pub fn size(&self) -> T {
+        self.width() * self.height() * self.depth()
+    }
+
+    /// Returns whether a [`Pos3<T>`] is on the boundary of this [`Volume<T>`].
+    #[inline]
+    pub fn on_boundary(&self, pos: &Pos3<T>) -> bool {
+        pos.x == self.min_x || pos.x == self.max_x
+            || pos.y == self.min_y || pos.y == self.max_y
+            || pos.z == self.min_z || pos.z == self.max_z
+    }
+
+    /// Returns the wrapped [`Pos3<T>`] which is inside this [`Volume<T>`].
+    #[inline]
+    pub fn wrap(&self, pos: &Pos3<T>) -> Pos3<T> {
+        let size = Pos3 { x: self.width(), y: self.height(), z: self.depth() };
+        (*pos - self.min()).modulo(&size) + self.min()
+    }
+}
+
+impl<T: Copy + Num + CheckedAdd + CheckedSub + PartialOrd> Volume<T> {
+    /// Returns the in range [`Pos3<T>`]s sharing a face with a [`Pos3<T>`].
+    #[inline]
+    pub fn face_neighbours<'a>(&'a self, pos: &'a Pos3<T>) -> impl Iterator<Item = Pos3<T>> + 'a {
+        pos.face_neighbours().filter(|p| self.has(p))
+    }
+
+    /// Returns the in range [`Pos3<T>`]s sharing a face, an edge or a corner with a [`Pos3<T>`].
+    #[inline]
+    pub fn all_neighbours<'a>(&'a self, pos: &'a Pos3<T>) -> impl Iterator<Item = Pos3<T>> + 'a {
+        pos.all_neighbours().filter(|p| self.has(p))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_into_iter() {
+        let volume = Volume { max_x: 5, max_y: 10, max_z: 3, min_x: -5, min_y: -10, min_z: 1 };
+        let sut = volume.into_iter();
+        assert_eq!(sut, VolumeIterator { volume, current_x: -5, current_y: -10, current_z: 1 });
+    }
+
+    #[test]
+    fn test_new() {
+        let sut = Volume::new(10, 10, 10, 0, 0, 0);
+        assert_eq!(sut, Ok(Volume { max_x: 10, max_y: 10, max_z: 10, min_x: 0, min_y: 0, min_z: 0 }));
+
+        let sut = Volume::new(10, 10, -1, 0, 0, 0);
+        assert_eq!(sut, Err(Error::VolumeBoundary));
+    }
+
+    #[test]
+    fn test_from_pos() {
+        let sut = Volume::from_pos(&Pos3 { x: -1, y: 0, z: 1 }, &Pos3 { x: 1, y: 2, z: 3 }).unwrap();
+        assert_eq!(sut, Volume { max_x: 1, max_y: 2, max_z: 3, min_x: -1, min_y: 0, min_z: 1 });
+        assert_eq!(sut.min(), Pos3 { x: -1, y: 0, z: 1 });
+        assert_eq!(sut.max(), Pos3 { x: 1, y: 2, z: 3 });
+
+        let sut = Volume::from_pos(&Pos3 { x: 1, y: 0, z: 0 }, &Pos3 { x: 0, y: 0, z: 0 });
+        assert_eq!(sut, Err(Error::VolumeBoundary));
+    }
+
+    #[test]
+    fn test_bounding() {
+        let sut = Volume::bounding([Pos3 { x: 1, y: -2, z: 3 }, Pos3 { x: -1, y: 5, z: 0 }, Pos3 { x: 0, y: 0, z: 7 }]);
+        assert_eq!(sut, Some(Volume { max_x: 1, max_y: 5, max_z: 7, min_x: -1, min_y: -2, min_z: 0 }));
+        assert_eq!(Volume::<i32>::bounding([]), None);
+    }
+
+    #[test]
+    fn test_has() {
+        let sut = Volume { max_x: 2, max_y: 2, max_z: 2, min_x: 0, min_y: 0, min_z: 0 };
+        assert!(sut.has(&Pos3 { x: 0, y: 0, z: 0 }));
+        assert!(sut.has(&Pos3 { x: 2, y: 1, z: 2 }));
+        assert!(!sut.has(&Pos3 { x: 2, y: 1, z: 3 }));
+        assert!(!sut.has(&Pos3 { x: -1, y: 1, z: 1 }));
+
+        let sut: Vec<_> = sut.filter_pos([Pos3 { x: 1, y: 1, z: 1 }, Pos3 { x: 1, y: 1, z: -1 }]).collect();
+        assert_eq!(sut, vec![Pos3 { x: 1, y: 1, z: 1 }]);
+    }
+
+    #[test]
+    fn test_size() {
+        let sut = Volume { max_x: 2, max_y: 3, max_z: 4, min_x: 0, min_y: 0, min_z: 0 };
+        assert_eq!(sut.width(), 3);
+        assert_eq!(sut.height(), 4);
+        assert_eq!(sut.depth(), 5);
+        assert_eq!(sut.size(), 60);
+    }
+
+    #[test]
+    fn test_on_boundary() {
+        let sut = Volume { max_x: 2, max_y: 2, max_z: 2, min_x: 0, min_y: 0, min_z: 0 };
+        assert!(sut.on_boundary(&Pos3 { x: 1, y: 1, z: 0 }));
+        assert!(sut.on_boundary(&Pos3 { x: 2, y: 1, z: 1 }));
+        assert!(!sut.on_boundary(&Pos3 { x: 1, y: 1, z: 1 }));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let sut = Volume { max_x: 2, max_y: 2, max_z: 2, min_x: 0, min_y: 0, min_z: 0 };
+        assert_eq!(sut.wrap(&Pos3 { x: 3, y: -1, z: 1 }), Pos3 { x: 0, y: 2, z: 1 });
+
+        let sut = Volume { max_x: 1, max_y: 1, max_z: 1, min_x: -1, min_y: -1, min_z: -1 };
+        assert_eq!(sut.wrap(&Pos3 { x: 2, y: -2, z: 0 }), Pos3 { x: -1, y: 1, z: 0 });
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let sut = Volume { max_x: 2, max_y: 2, max_z: 2, min_x: 0, min_y: 0, min_z: 0 };
+        assert_eq!(sut.face_neighbours(&Pos3 { x: 1, y: 1, z: 1 }).count(), 6);
+        assert_eq!(sut.face_neighbours(&Pos3 { x: 0, y: 0, z: 0 }).count(), 3);
+        assert_eq!(sut.all_neighbours(&Pos3 { x: 1, y: 1, z: 1 }).count(), 26);
+        assert_eq!(sut.all_neighbours(&Pos3 { x: 2, y: 2, z: 2 }).count(), 7);
+    }
+}
@@ -0,0 +1,60 @@
+use num::Num;
+use crate::geo::pos3::Pos3;
+use crate::geo::volume::Volume;
+
+/// Represents an iterator that iterates through all the [`Pos3<T>`]s inside a [`Volume<T>`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VolumeIterator<T> {
+    pub(crate) volume: Volume<T>,
+    pub(crate) current_x: T,
+    pub(crate) current_y: T,
+    pub(crate) current_z: T,
+}
+
+impl<T: Copy + Num + PartialOrd> Iterator for VolumeIterator<T> {
+    type Item = Pos3<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_z > self.volume.max_z {
+            return None;
+        }
+
+        let result = Pos3 { x: self.current_x, y: self.current_y, z: self.current_z };
+        if self.current_x < self.volume.max_x {
+            self.current_x = self.current_x + T::one();
+        } else if self.current_y < self.volume.max_y {
+            self.current_x = self.volume.min_x;
+            self.current_y = self.current_y + T::one();
+        } else {
+            self.current_x = self.volume.min_x;
+            self.current_y = self.volume.min_y;
+            self.current_z = self.current_z + T::one();
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iter() {
+        let volume = Volume { max_x: 1, max_y: 2, max_z: 0, min_x: 0, min_y: 1, min_z: -1 };
+        let sut: Vec<Pos3<_>> = volume.into_iter().collect();
+        assert_eq!(sut, vec![
+            Pos3 { x: 0, y: 1, z: -1 },
+            Pos3 { x: 1, y: 1, z: -1 },
+            Pos3 { x: 0, y: 2, z: -1 },
+            Pos3 { x: 1, y: 2, z: -1 },
+            Pos3 { x: 0, y: 1, z: 0 },
+            Pos3 { x: 1, y: 1, z: 0 },
+            Pos3 { x: 0, y: 2, z: 0 },
+            Pos3 { x: 1, y: 2, z: 0 },
+        ]);
+
+        let volume = Volume { max_x: 0, max_y: 0, max_z: 0, min_x: 0, min_y: 0, min_z: 0 };
+        let sut: Vec<Pos3<_>> = volume.into_iter().collect();
+        assert_eq!(sut, vec![Pos3 { x: 0, y: 0, z: 0 }]);
+    }
+}
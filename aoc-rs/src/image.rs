@@ -0,0 +1,173 @@
+#![cfg(feature = "std")]
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use crate::geo::area::Area;
+use crate::geo::grid::Grid;
+use crate::geo::pos::Pos;
+
+/// A color as red, green and blue components.
+pub type Rgb = [u8; 3];
+
+/// An RGB image which can be written to disk, for inspecting grids and simulations.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Returns a new [`Image`] with one pixel per cell of a [`Grid<T>`], colored by `color`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut color: impl FnMut(&T) -> Rgb) -> Self {
+        Self { width: grid.width, height: grid.height, pixels: grid.data.iter().map(&mut color).collect() }
+    }
+
+    /// Returns a new [`Image`] with one pixel per [`Pos<i64>`] in an `Area<i64>`, colored by `color` from the number of
+    /// positions at each pixel. The minimum Y of the area becomes the top row, and positions outside it are ignored.
+    pub fn from_positions(
+        positions: impl IntoIterator<Item = Pos<i64>>,
+        area: &Area<i64>,
+        mut color: impl FnMut(usize) -> Rgb,
+    ) -> Self {
+        let (width, height) = (area.cols() as usize, area.rows() as usize);
+        let mut counts = vec![0; width * height];
+        for pos in positions.into_iter().filter(|pos| area.has(pos)) {
+            counts[(pos.y - area.min_y) as usize * width + (pos.x - area.min_x) as usize] += 1;
+        }
+        Self { width, height, pixels: counts.into_iter().map(&mut color).collect() }
+    }
+
+    /// Returns the pixels as a flat list of red, green and blue bytes.
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    /// Writes this [`Image`] in the binary PPM format.
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Saves this [`Image`] to a file in the binary PPM format.
+    #[inline]
+    pub fn save_ppm(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_ppm(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Writes this [`Image`] in the PNG format.
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.to_bytes())?;
+        Ok(writer.finish()?)
+    }
+
+    /// Saves this [`Image`] to a file in the PNG format.
+    #[cfg(feature = "png")]
+    #[inline]
+    pub fn save_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.write_png(BufWriter::new(File::create(path)?))
+    }
+}
+
+/// Writes a sequence of equally sized [`Image`]s as an endlessly looping animated GIF, showing each frame for `delay`
+/// hundredths of a second.
+#[cfg(feature = "gif")]
+pub fn write_gif<'a>(frames: impl IntoIterator<Item = &'a Image>, delay: u16, writer: impl Write) -> io::Result<()> {
+    let mut frames = frames.into_iter().peekable();
+    let Some(first) = frames.peek() else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "no frames to write"));
+    };
+    let (width, height) = (first.width, first.height);
+    let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "frames are too large for a gif"));
+    };
+
+    let mut encoder = gif::Encoder::new(writer, gif_width, gif_height, &[]).map_err(io::Error::other)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+    for image in frames {
+        if image.width != width || image.height != height {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "frames have different sizes"));
+        }
+
+        let mut frame = gif::Frame::from_rgb(gif_width, gif_height, &image.to_bytes());
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+/// Saves a sequence of equally sized [`Image`]s to a file as an endlessly looping animated GIF. See [`write_gif`].
+#[cfg(feature = "gif")]
+#[inline]
+pub fn save_gif<'a>(frames: impl IntoIterator<Item = &'a Image>, delay: u16, path: impl AsRef<Path>) -> io::Result<()> {
+    write_gif(frames, delay, BufWriter::new(File::create(path)?))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use crate::geo::grid::CharGrid;
+    use super::*;
+
+    const BLACK: Rgb = [0, 0, 0];
+    const WHITE: Rgb = [255, 255, 255];
+
+    #[test]
+    fn test_from_grid() {
+        let grid = CharGrid::from_str("#.\n.#").unwrap();
+        let sut = Image::from_grid(&grid, |&x| if x == b'#' { WHITE } else { BLACK });
+        assert_eq!(sut.pixels, vec![WHITE, BLACK, BLACK, WHITE]);
+        assert_eq!(sut.to_bytes(), vec![255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_from_positions() {
+        let area = Area { max_x: 1, max_y: 0, min_x: -1, min_y: -1 };
+        let positions = [Pos { x: -1, y: -1 }, Pos { x: -1, y: -1 }, Pos { x: 1, y: 0 }, Pos { x: 5, y: 5 }];
+        let sut = Image::from_positions(positions, &area, |count| [count as u8; 3]);
+        assert_eq!((sut.width, sut.height), (3, 2));
+        assert_eq!(sut.pixels, vec![[2; 3], [0; 3], [0; 3], [0; 3], [0; 3], [1; 3]]);
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let sut = Image { width: 2, height: 1, pixels: vec![WHITE, [1, 2, 3]] };
+        let mut result = Vec::new();
+        sut.write_ppm(&mut result).unwrap();
+        assert_eq!(result, b"P6\n2 1\n255\n\xff\xff\xff\x01\x02\x03");
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_write_png() {
+        let sut = Image { width: 2, height: 1, pixels: vec![WHITE, BLACK] };
+        let mut result = Vec::new();
+        sut.write_png(&mut result).unwrap();
+        assert_eq!(&result[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[cfg(feature = "gif")]
+    #[test]
+    fn test_write_gif() {
+        let frames = [
+            Image { width: 2, height: 1, pixels: vec![WHITE, BLACK] },
+            Image { width: 2, height: 1, pixels: vec![BLACK, WHITE] },
+        ];
+        let mut result = Vec::new();
+        write_gif(&frames, 10, &mut result).unwrap();
+        assert_eq!(&result[..6], b"GIF89a");
+
+        let frames = [frames[0].clone(), Image { width: 1, height: 1, pixels: vec![BLACK] }];
+        assert!(write_gif(&frames, 10, Vec::new()).is_err());
+        assert!(write_gif(&[], 10, Vec::new()).is_err());
+    }
+}
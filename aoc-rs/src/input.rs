@@ -3,6 +3,9 @@
 use std::fs::File;
 use std::io;
 use std::io::{read_to_string, BufRead};
+use std::str::FromStr;
+use crate::error::{Error, Reason};
+use crate::geo::pos::Pos;
 
 /// Returns all lines from a file.
 #[inline]
@@ -17,13 +20,225 @@ pub fn get_lines(filename: &str) -> io::Result<io::Lines<io::BufReader<File>>> {
     Ok(io::BufReader::new(file).lines())
 }
 
-/// Returns all text from a file.
+/// Returns all text from a file, with normalized line endings and trailing whitespace. See [`normalize`].
 #[inline]
 pub fn get_text(filename: &str) -> Result<String, io::Error> {
+    Ok(normalize(&get_raw_text(filename)?))
+}
+
+/// Returns all text from a file as is.
+#[inline]
+pub fn get_raw_text(filename: &str) -> Result<String, io::Error> {
     let file = File::open(filename)?;
     read_to_string(file)
 }
 
+/// Returns the text with `\r\n` line endings replaced by `\n`, trailing whitespace removed from each line and trailing
+/// blank lines removed.
+pub fn normalize(s: &str) -> String {
+    let lines: Vec<_> = s.lines().map(str::trim_end).collect();
+    lines.join("\n").trim_end_matches('\n').to_string()
+}
+
+/// A block of consecutive non-blank lines.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Section<'a> {
+    /// The line number of the first line, starting from 1.
+    pub line: usize,
+    pub text: &'a str,
+}
+
+impl Section<'_> {
+    /// Parses the section, reporting errors at their line in the whole input.
+    #[inline]
+    pub fn parse<T>(&self) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        T::from_str(self.text).map_err(|e| e.into().offset_line(self.line))
+    }
+}
+
+/// The `key: value` lines of some text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Records<'a> {
+    entries: Vec<(usize, &'a str, &'a str)>,
+}
+
+impl<'a> Records<'a> {
+    /// Returns the value for a key.
+    pub fn get(&self, key: &'static str) -> Result<&'a str, Error> {
+        self.entries
+            .iter()
+            .find(|(_, k, _)| *k == key)
+            .map(|(_, _, v)| *v)
+            .ok_or_else(|| Error::parse("", Reason::Missing(key)))
+    }
+
+    /// Parses the value for a key.
+    #[inline]
+    pub fn parse<T>(&self, key: &'static str) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        self.parse_with(key, |x| T::from_str(x).map_err(Into::into))
+    }
+
+    /// Parses the value for a key with a function, adding the line number to any error.
+    pub fn parse_with<T, F>(&self, key: &'static str, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&str) -> Result<T, Error>,
+    {
+        let (line, _, value) = self
+            .entries
+            .iter()
+            .find(|(_, k, _)| *k == key)
+            .ok_or_else(|| Error::parse("", Reason::Missing(key)))?;
+        f(value).map_err(|e| e.with_text(value).at_line(*line))
+    }
+
+    /// Returns the number of records.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no records.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Parses each line with a function, adding the line number to any error.
+pub fn parse_lines<T, F>(s: &str, mut f: F) -> Result<Vec<T>, Error>
+where
+    F: FnMut(&str) -> Result<T, Error>,
+{
+    s.lines()
+        .enumerate()
+        .map(|(idx, line)| f(line).map_err(|e| e.at_line(idx + 1)))
+        .collect()
+}
+
+/// Returns all integers in a line, such as `-3` and `4` in `p=-3,4`.
+pub fn ints<T>(line: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    let bytes = line.as_bytes();
+    let mut result = Vec::new();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let start = idx;
+        if bytes[idx] == b'-' {
+            idx += 1;
+        }
+        let digits = idx;
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        if idx == digits {
+            idx = start + 1;
+            continue;
+        }
+        let value = T::from_str(&line[start..idx]).map_err(|e| e.into().with_text(line).at_column(start + 1))?;
+        result.push(value);
+    }
+    Ok(result)
+}
+
+/// Splits text into sections separated by blank lines.
+pub fn sections(s: &str) -> Vec<Section<'_>> {
+    let mut result = Vec::new();
+    let mut start = None;
+    let (mut offset, mut end) = (0, 0);
+    for (idx, line) in s.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((line, from)) = start.take() {
+                result.push(Section { line, text: &s[from..end] });
+            }
+        } else {
+            start.get_or_insert((idx + 1, offset));
+            end = offset + line.trim_end_matches(['\n', '\r']).len();
+        }
+        offset += line.len();
+    }
+    if let Some((line, from)) = start {
+        result.push(Section { line, text: &s[from..end] });
+    }
+    result
+}
+
+/// Splits a `key: value` line into its trimmed key and value.
+pub fn record(line: &str) -> Result<(&str, &str), Error> {
+    line.split_once(':')
+        .map(|(k, v)| (k.trim(), v.trim()))
+        .ok_or_else(|| Error::parse(line, Reason::Missing("separator `:`")))
+}
+
+/// Parses the `key: value` lines of some text, skipping blank lines.
+pub fn records(s: &str) -> Result<Records<'_>, Error> {
+    let entries = s
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| record(line).map(|(k, v)| (idx + 1, k, v)).map_err(|e| e.at_line(idx + 1)))
+        .collect::<Result<_, _>>()?;
+    Ok(Records { entries })
+}
+
+/// Parses a comma separated list, trimming each item. Returns an empty list for blank text.
+pub fn list<T>(s: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    if s.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut column = 1;
+    s.split(',')
+        .map(|item| {
+            let start = column + item.len() - item.trim_start().len();
+            column += item.len() + 1;
+            T::from_str(item.trim()).map_err(|e| e.into().with_text(s).at_column(start))
+        })
+        .collect()
+}
+
+/// Parses a whitespace separated list, failing on any item which does not parse.
+pub fn words<T>(s: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    s.split_whitespace()
+        .map(|item| {
+            let column = item.as_ptr() as usize - s.as_ptr() as usize + 1;
+            T::from_str(item).map_err(|e| e.into().with_text(s).at_column(column))
+        })
+        .collect()
+}
+
+/// Parses an `x,y` pair into a [`Pos`].
+pub fn pos<T>(s: &str) -> Result<Pos<T>, Error>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    let (x, y) = s
+        .split_once(',')
+        .ok_or_else(|| Error::parse(s, Reason::Missing("separator `,`")))?;
+    let x = T::from_str(x.trim()).map_err(|e| e.into().with_text(s))?;
+    let y = T::from_str(y.trim()).map_err(|e| e.into().with_text(s))?;
+    Ok(Pos { x, y })
+}
+
 #[cfg(test)]
 mod test {
     use std::fs;
@@ -57,4 +272,117 @@ mod test {
         assert_eq!(sut, String::from("line 1\nline 2"));
         remove_file("test_get_text.txt").unwrap();
     }
+
+    #[test]
+    fn test_get_text_crlf() {
+        fs::write("test_get_text_crlf.txt", "line 1\r\n\r\nline 2\r\n").unwrap();
+        let sut = get_text("test_get_text_crlf.txt").unwrap();
+        assert_eq!(sut, String::from("line 1\n\nline 2"));
+        remove_file("test_get_text_crlf.txt").unwrap();
+    }
+
+    #[test]
+    fn test_get_raw_text() {
+        fs::write("test_get_raw_text.txt", "line 1\r\nline 2\n").unwrap();
+        let sut = get_raw_text("test_get_raw_text.txt").unwrap();
+        assert_eq!(sut, String::from("line 1\r\nline 2\n"));
+        remove_file("test_get_raw_text.txt").unwrap();
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb\r\n\r\n"), "a\nb");
+        assert_eq!(normalize("a\n\nb\n"), "a\n\nb");
+        assert_eq!(normalize("#.# \n.#.\t\r\n  \n"), "#.#\n.#.");
+        assert_eq!(normalize(" a"), " a");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let sut = parse_lines("1\n2\n3", |x| Ok(i32::from_str(x)?));
+        assert_eq!(sut, Ok(vec![1, 2, 3]));
+
+        let sut = parse_lines("1\nx", |x| Ok(i32::from_str(x)?));
+        assert_eq!(sut, Err(Error::from(i32::from_str("x").unwrap_err()).at_line(2)));
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i64>("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(ints::<i32>("Button A: X+94, Y+34"), Ok(vec![94, 34]));
+        assert_eq!(ints::<i32>("3   4"), Ok(vec![3, 4]));
+        assert_eq!(ints::<i32>("a - b"), Ok(vec![]));
+
+        let sut = ints::<u8>("1 300").unwrap_err();
+        assert_eq!(format!("{}", sut), "column 3: invalid integer (too large) in `1 300`");
+    }
+
+    #[test]
+    fn test_sections() {
+        let sut = sections("a\nb\n\n\nc\r\n\r\nd\n");
+        assert_eq!(sut, vec![
+            Section { line: 1, text: "a\nb" },
+            Section { line: 5, text: "c" },
+            Section { line: 7, text: "d" },
+        ]);
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_section_parse() {
+        let sut = sections("1\n\nx");
+        assert_eq!(sut[0].parse::<i32>(), Ok(1));
+        assert_eq!(sut[1].parse::<i32>(), Err(Error::from(i32::from_str("x").unwrap_err()).at_line(3)));
+    }
+
+    #[test]
+    fn test_record() {
+        assert_eq!(record("Register A: 729"), Ok(("Register A", "729")));
+        assert_eq!(record("190: 10 19"), Ok(("190", "10 19")));
+        assert_eq!(record("abc"), Err(Error::parse("abc", Reason::Missing("separator `:`"))));
+    }
+
+    #[test]
+    fn test_records() {
+        let sut = records("Register A: 729\nRegister B: x\n\nProgram: 0,1").unwrap();
+        assert_eq!(sut.len(), 3);
+        assert_eq!(sut.get("Program"), Ok("0,1"));
+        assert_eq!(sut.parse::<u64>("Register A"), Ok(729));
+        let err = sut.parse::<u64>("Register B").unwrap_err();
+        assert_eq!(format!("{}", err), "line 2: invalid integer (invalid digit) in `x`");
+        assert_eq!(sut.get("Register C"), Err(Error::parse("", Reason::Missing("Register C"))));
+        assert_eq!(sut.parse_with("Program", list::<i8>), Ok(vec![0, 1]));
+
+        let sut = records("a: 1\nb");
+        assert_eq!(sut, Err(Error::parse("b", Reason::Missing("separator `:`")).at_line(2)));
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(list::<i8>("0,1,5,4"), Ok(vec![0, 1, 5, 4]));
+        assert_eq!(list::<String>("r, wr, b"), Ok(vec![String::from("r"), String::from("wr"), String::from("b")]));
+        assert_eq!(list::<i32>(""), Ok(vec![]));
+
+        let sut = list::<i32>("1, 2, x").unwrap_err();
+        assert_eq!(format!("{}", sut), "column 7: invalid integer (invalid digit) in `1, 2, x`");
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(words::<i64>("10 19"), Ok(vec![10, 19]));
+        assert_eq!(words::<i64>("  81 40\t27 "), Ok(vec![81, 40, 27]));
+        assert_eq!(words::<i64>(""), Ok(vec![]));
+
+        let sut = words::<i64>("10 x 19").unwrap_err();
+        assert_eq!(format!("{}", sut), "column 4: invalid integer (invalid digit) in `10 x 19`");
+    }
+
+    #[test]
+    fn test_pos() {
+        assert_eq!(pos::<usize>("5,4"), Ok(Pos { x: 5, y: 4 }));
+        assert_eq!(pos::<i64>("-1, 2"), Ok(Pos { x: -1, y: 2 }));
+        assert_eq!(pos::<usize>("5"), Err(Error::parse("5", Reason::Missing("separator `,`"))));
+        assert!(pos::<usize>("5,-4").is_err());
+    }
 }
@@ -3,6 +3,13 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+pub mod collections;
+pub mod cycle;
+pub mod error;
 pub mod geo;
+pub mod image;
 pub mod input;
 pub mod math;
+pub mod memo;
+pub mod search;
+pub mod solution;
@@ -0,0 +1,236 @@
+#![cfg(feature = "std")]
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Storage for the results of a [`Memo`].
+pub trait Cache<K, V> {
+    /// Returns the result stored for a key.
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Stores the result for a key.
+    fn insert(&mut self, key: K, value: V);
+
+    /// Removes every stored result.
+    fn clear(&mut self);
+
+    /// Returns the number of stored results.
+    fn len(&self) -> usize;
+
+    /// Returns true if no results are stored.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash, V> Cache<K, V> for HashMap<K, V> {
+    #[inline]
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    #[inline]
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        HashMap::clear(self)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+/// A [`Cache`] for small dense `usize` keys, stored in a fixed-size array. Keys outside the array are not stored.
+#[derive(Clone, Debug)]
+pub struct ArrayCache<V, const N: usize> {
+    values: [Option<V>; N],
+    len: usize,
+}
+
+impl<V, const N: usize> ArrayCache<V, N> {
+    /// Returns a new empty [`ArrayCache`].
+    #[inline]
+    pub fn new() -> Self {
+        Self { values: [const { None }; N], len: 0 }
+    }
+}
+
+impl<V, const N: usize> Default for ArrayCache<V, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, const N: usize> Cache<usize, V> for ArrayCache<V, N> {
+    #[inline]
+    fn get(&self, key: &usize) -> Option<&V> {
+        self.values.get(*key)?.as_ref()
+    }
+
+    #[inline]
+    fn insert(&mut self, key: usize, value: V) {
+        if let Some(slot) = self.values.get_mut(key) {
+            if slot.replace(value).is_none() {
+                self.len += 1;
+            }
+        }
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.values.iter_mut().for_each(|x| *x = None);
+        self.len = 0;
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Memoizes the results of a function, counting cache hits and misses. Backed by a [`HashMap`] unless another
+/// [`Cache`] is given.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V, C = HashMap<K, V>> {
+    cache: C,
+    hits: usize,
+    misses: usize,
+    marker: PhantomData<(K, V)>,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    /// Returns a new [`Memo`] backed by an empty [`HashMap`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_cache(HashMap::new())
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V: Clone, C: Cache<K, V>> Memo<K, V, C> {
+    /// Returns a new [`Memo`] backed by a [`Cache`].
+    #[inline]
+    pub fn with_cache(cache: C) -> Self {
+        Self { cache, hits: 0, misses: 0, marker: PhantomData }
+    }
+
+    /// Returns the stored result for a key, or computes and stores it. `compute` receives this [`Memo`] so that
+    /// recursive calls share the cache.
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Removes every stored result. The hit and miss counts are kept.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Returns the number of stored results.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns true if no results are stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Returns the number of lookups which found a stored result.
+    #[inline]
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns the number of lookups which had to compute a result.
+    #[inline]
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_insert_with(n, |memo| fib(n - 1, memo) + fib(n - 2, memo))
+    }
+
+    fn fib_array(n: usize, memo: &mut Memo<usize, u64, ArrayCache<u64, 16>>) -> u64 {
+        if n < 2 {
+            return n as u64;
+        }
+        memo.get_or_insert_with(n, |memo| fib_array(n - 1, memo) + fib_array(n - 2, memo))
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut sut = Memo::new();
+        assert_eq!(fib(90, &mut sut), 2880067194370816120);
+        assert_eq!(sut.len(), 89);
+        assert_eq!(sut.misses(), 89);
+        assert_eq!(sut.hits(), 87);
+
+        assert_eq!(fib(90, &mut sut), 2880067194370816120);
+        assert_eq!(sut.hits(), 88);
+
+        sut.clear();
+        assert!(sut.is_empty());
+        assert_eq!(sut.hits(), 88);
+    }
+
+    #[test]
+    fn test_array_cache() {
+        let mut sut = Memo::with_cache(ArrayCache::new());
+        assert_eq!(fib_array(15, &mut sut), 610);
+        assert_eq!(sut.len(), 14);
+        assert_eq!(sut.misses(), 14);
+        assert_eq!(sut.hits(), 12);
+
+        assert_eq!(fib_array(18, &mut sut), 2584);
+        assert_eq!(sut.len(), 14);
+
+        sut.clear();
+        assert!(sut.is_empty());
+    }
+
+    #[test]
+    fn test_array_cache_insert() {
+        let mut sut: ArrayCache<&str, 2> = ArrayCache::new();
+        sut.insert(1, "a");
+        sut.insert(1, "b");
+        sut.insert(5, "c");
+        assert_eq!(sut.len(), 1);
+        assert_eq!(sut.get(&1), Some(&"b"));
+        assert_eq!(sut.get(&0), None);
+        assert_eq!(sut.get(&5), None);
+    }
+}
@@ -0,0 +1,470 @@
+#![cfg(feature = "std")]
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use num::Zero;
+
+/// The result of a graph search.
+#[derive(Clone, Debug)]
+pub struct SearchResult<N, C> {
+    /// The first node which satisfied the goal predicate, or [`None`] if no goal was reached.
+    pub goal: Option<N>,
+
+    /// The cost to reach each discovered node.
+    pub costs: HashMap<N, C>,
+
+    /// The parent of each discovered node, excluding the start node.
+    pub parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchResult<N, C> {
+    /// Returns the cost to reach a node, or [`None`] if the node was not discovered.
+    #[inline]
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Returns the cost to reach the goal, or [`None`] if no goal was reached.
+    #[inline]
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// Returns the path from the start node to a node, or [`None`] if the node was not discovered.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+
+        let mut result = vec![node.clone()];
+        let mut current = node;
+        while let Some(parent) = self.parents.get(current) {
+            result.push(parent.clone());
+            current = parent;
+        }
+        result.reverse();
+        Some(result)
+    }
+
+    /// Returns the path from the start node to the goal, or [`None`] if no goal was reached.
+    #[inline]
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/// The result of an all shortest paths search.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<N, C> {
+    /// The goal nodes reached with the lowest cost.
+    pub goals: Vec<N>,
+
+    /// The lowest cost to reach each discovered node.
+    pub costs: HashMap<N, C>,
+
+    /// Every predecessor of each discovered node which reaches it with the lowest cost.
+    pub predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> ShortestPaths<N, C> {
+    /// Returns the cost to reach the goals, or [`None`] if no goal was reached.
+    #[inline]
+    pub fn goal_cost(&self) -> Option<C> {
+        self.costs.get(self.goals.first()?).copied()
+    }
+
+    /// Returns every distinct node on any shortest path from the start node to a goal.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        let mut result: HashSet<&N> = HashSet::with_capacity(self.costs.len());
+        let mut stack: Vec<&N> = self.goals.iter().collect();
+        while let Some(node) = stack.pop() {
+            if result.insert(node) {
+                stack.extend(self.predecessors.get(node).into_iter().flatten());
+            }
+        }
+        result.into_iter()
+    }
+
+    /// Returns the number of distinct shortest paths from the start node to any goal.
+    pub fn count_paths(&self) -> usize {
+        let mut nodes: Vec<_> = self.nodes().collect();
+        nodes.sort_by_key(|&n| self.costs[n]);
+
+        let mut counts: HashMap<&N, usize> = HashMap::with_capacity(nodes.len());
+        for node in nodes {
+            let count = match self.predecessors.get(node) {
+                Some(preds) if !preds.is_empty() => preds.iter().map(|p| counts[p]).sum(),
+                _ => 1,
+            };
+            counts.insert(node, count);
+        }
+        self.goals.iter().map(|g| counts[g]).sum()
+    }
+}
+
+/// An entry in the priority queue, ordered so that the lowest priority is popped first.
+#[derive(Clone, Debug)]
+struct Visit<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Visit<N, C> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Visit<N, C> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Visit<N, C> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Visit<N, C> {}
+
+/// Performs a [breadth-first search](https://en.wikipedia.org/wiki/Breadth-first_search), where the cost is the
+/// number of steps taken. Stops at the first node which satisfies `goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            return SearchResult { goal: Some(node), costs, parents };
+        }
+
+        let cost = costs[&node] + 1;
+        for next in successors(&node) {
+            if !costs.contains_key(&next) {
+                costs.insert(next.clone(), cost);
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    SearchResult { goal: None, costs, parents }
+}
+
+/// Performs a [depth-first search](https://en.wikipedia.org/wiki/Depth-first_search), where the cost is the depth
+/// at which a node was discovered. Stops at the first node which satisfies `goal`.
+pub fn dfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut parents = HashMap::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if goal(&node) {
+            return SearchResult { goal: Some(node), costs, parents };
+        }
+
+        let cost = costs[&node] + 1;
+        for next in successors(&node) {
+            if !costs.contains_key(&next) {
+                costs.insert(next.clone(), cost);
+                parents.insert(next.clone(), node.clone());
+                stack.push(next);
+            }
+        }
+    }
+    SearchResult { goal: None, costs, parents }
+}
+
+/// Performs a [Dijkstra search](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm), where `successors` returns
+/// each neighbouring node with the cost of moving to it. Stops at the first node which satisfies `goal`.
+#[inline]
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::zero(), goal)
+}
+
+/// Performs an [A* search](https://en.wikipedia.org/wiki/A*_search_algorithm), where `successors` returns each
+/// neighbouring node with the cost of moving to it, and `heuristic` estimates the remaining cost to a goal. Stops at
+/// the first node which satisfies `goal`.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::zero())]);
+    let mut parents = HashMap::new();
+    let mut pq = BinaryHeap::from([Visit { priority: heuristic(&start), cost: C::zero(), node: start }]);
+    while let Some(Visit { cost, node, .. }) = pq.pop() {
+        if cost > costs[&node] {
+            continue;
+        }
+        if goal(&node) {
+            return SearchResult { goal: Some(node), costs, parents };
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&c| next_cost < c) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), node.clone());
+                pq.push(Visit { priority: next_cost + heuristic(&next), cost: next_cost, node: next });
+            }
+        }
+    }
+    SearchResult { goal: None, costs, parents }
+}
+
+/// Performs a [Dijkstra search](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm) which records every equal
+/// cost predecessor of each node. Stops once every goal with the lowest cost has been reached. Edge costs must be
+/// positive.
+pub fn dijkstra_all<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::zero())]);
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut goals = Vec::new();
+    let mut best: Option<C> = None;
+    let mut pq = BinaryHeap::from([Visit { priority: C::zero(), cost: C::zero(), node: start }]);
+    while let Some(Visit { cost, node, .. }) = pq.pop() {
+        if cost > costs[&node] {
+            continue;
+        }
+        if best.is_some_and(|b| cost > b) {
+            break;
+        }
+        if goal(&node) {
+            best = Some(cost);
+            goals.push(node);
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            match costs.get(&next) {
+                Some(&c) if next_cost > c => continue,
+                Some(&c) if next_cost == c => {
+                    predecessors.entry(next).or_default().push(node.clone());
+                    continue;
+                }
+                _ => {}
+            }
+            costs.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), vec![node.clone()]);
+            pq.push(Visit { priority: next_cost, cost: next_cost, node: next });
+        }
+    }
+    ShortestPaths { goals, costs, predecessors }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geo::area::Area;
+    use crate::geo::direction::{Direction, DirectionalPos};
+    use crate::geo::pos::PosIdx;
+    use super::*;
+
+    fn line_successors(node: &i32) -> Vec<i32> {
+        [node - 1, node + 1].into_iter().filter(|x| (0..=10).contains(x)).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let sut = bfs(0, line_successors, |&x| x == 7);
+        assert_eq!(sut.goal, Some(7));
+        assert_eq!(sut.goal_cost(), Some(7));
+        assert_eq!(sut.goal_path(), Some(vec![0, 1, 2, 3, 4, 5, 6, 7]));
+
+        let sut = bfs(0, line_successors, |_| false);
+        assert_eq!(sut.goal, None);
+        assert_eq!(sut.costs.len(), 11);
+        assert_eq!(sut.cost(&10), Some(10));
+        assert_eq!(sut.cost(&11), None);
+    }
+
+    #[test]
+    fn test_bfs_grid() {
+        let area = Area { max_x: 4, max_y: 4, min_x: 0, min_y: 0 };
+        let walls = [PosIdx::new(1, 0), PosIdx::new(1, 1), PosIdx::new(1, 2), PosIdx::new(1, 3)];
+        let sut = bfs(
+            PosIdx::origin(),
+            |p| area.neighbours(p, 1, Direction::cross()).filter(|n| !walls.contains(n)).collect::<Vec<_>>(),
+            |&p| p == PosIdx::new(2, 0),
+        );
+        assert_eq!(sut.goal_cost(), Some(10));
+        assert_eq!(sut.goal_path().unwrap().len(), 11);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let sut = dfs(0, line_successors, |&x| x == 7);
+        assert_eq!(sut.goal, Some(7));
+        assert_eq!(sut.goal_path(), Some(vec![0, 1, 2, 3, 4, 5, 6, 7]));
+
+        let sut = dfs(5, line_successors, |_| false);
+        assert_eq!(sut.goal, None);
+        assert_eq!(sut.costs.len(), 11);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let sut = dijkstra(0, |&x: &i32| [(x + 1, 1), (x + 5, 3)], |&x| x == 10);
+        assert_eq!(sut.goal_cost(), Some(6));
+        assert_eq!(sut.goal_path(), Some(vec![0, 5, 10]));
+
+        let sut = dijkstra(0, |&x: &i32| if x < 3 { vec![(x + 1, 1)] } else { vec![] }, |&x| x == 10);
+        assert_eq!(sut.goal, None);
+        assert_eq!(sut.goal_cost(), None);
+        assert_eq!(sut.cost(&3), Some(3));
+    }
+
+    #[test]
+    fn test_dijkstra_directional() {
+        let area = Area { max_x: 3, max_y: 3, min_x: 0, min_y: 0 };
+        let start = DirectionalPos::new(PosIdx::origin(), Direction::Right);
+        let sut = dijkstra(
+            start,
+            |d: &DirectionalPos<usize>| {
+                let mut result = vec![
+                    (d.update_direction(d.direction.left()), 1000),
+                    (d.update_direction(d.direction.right()), 1000),
+                ];
+                if let Some(next) = d.checked_next(1).filter(|n| area.has(&n.pos)) {
+                    result.push((next, 1));
+                }
+                result
+            },
+            |d| d.pos == PosIdx::new(3, 3),
+        );
+        assert_eq!(sut.goal_cost(), Some(1006));
+        assert_eq!(sut.goal_path().unwrap()[0], start);
+    }
+
+    #[test]
+    fn test_astar() {
+        let area = Area { max_x: 9, max_y: 9, min_x: 0, min_y: 0 };
+        let end = PosIdx::new(9, 9);
+        let sut = astar(
+            PosIdx::origin(),
+            |p| area.neighbours(p, 1, Direction::cross()).map(|n| (n, 1)).collect::<Vec<_>>(),
+            |p| p.manhattan_unsigned(&end),
+            |&p| p == end,
+        );
+        assert_eq!(sut.goal_cost(), Some(18));
+        assert_eq!(sut.goal_path().unwrap().len(), 19);
+        assert!(sut.costs.len() < area.size());
+    }
+
+    #[test]
+    fn test_path() {
+        let sut = bfs(3, line_successors, |_| false);
+        assert_eq!(sut.path(&3), Some(vec![3]));
+        assert_eq!(sut.path(&0), Some(vec![3, 2, 1, 0]));
+        assert_eq!(sut.path(&42), None);
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        // two equal cost routes from 0 to 3: 0 -> 1 -> 3 and 0 -> 2 -> 3
+        let edges = |&x: &i32| match x {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 | 2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        };
+        let sut = dijkstra_all(0, edges, |&x| x == 3);
+        assert_eq!(sut.goals, vec![3]);
+        assert_eq!(sut.goal_cost(), Some(2));
+        assert_eq!(sut.count_paths(), 2);
+
+        let mut nodes: Vec<_> = sut.nodes().copied().collect();
+        nodes.sort();
+        assert_eq!(nodes, vec![0, 1, 2, 3]);
+
+        let sut = dijkstra_all(0, edges, |&x| x == 42);
+        assert!(sut.goals.is_empty());
+        assert_eq!(sut.goal_cost(), None);
+        assert_eq!(sut.count_paths(), 0);
+        assert_eq!(sut.nodes().count(), 0);
+    }
+
+    #[test]
+    fn test_dijkstra_all_grid() {
+        let area = Area { max_x: 2, max_y: 2, min_x: 0, min_y: 0 };
+        let sut = dijkstra_all(
+            PosIdx::origin(),
+            |p| area.neighbours(p, 1, [Direction::Up, Direction::Right]).map(|n| (n, 1)).collect::<Vec<_>>(),
+            |&p| p == PosIdx::new(2, 2),
+        );
+        assert_eq!(sut.goal_cost(), Some(4));
+        assert_eq!(sut.count_paths(), 6);
+        assert_eq!(sut.nodes().count(), 9);
+    }
+
+    #[test]
+    fn test_dijkstra_all_directional() {
+        let area = Area { max_x: 1, max_y: 1, min_x: 0, min_y: 0 };
+        let start = DirectionalPos::new(PosIdx::origin(), Direction::Right);
+        let sut = dijkstra_all(
+            start,
+            |d: &DirectionalPos<usize>| {
+                let mut result = vec![
+                    (d.update_direction(d.direction.left()), 1000),
+                    (d.update_direction(d.direction.right()), 1000),
+                ];
+                if let Some(next) = d.checked_next(1).filter(|n| area.has(&n.pos)) {
+                    result.push((next, 1));
+                }
+                result
+            },
+            |d| d.pos == PosIdx::new(1, 1),
+        );
+        assert_eq!(sut.goal_cost(), Some(1002));
+        assert_eq!(sut.goals, vec![DirectionalPos::new(PosIdx::new(1, 1), Direction::Up)]);
+        assert_eq!(sut.count_paths(), 1);
+
+        let seats: HashSet<_> = sut.nodes().map(|d| d.pos).collect();
+        assert_eq!(seats, HashSet::from([PosIdx::new(0, 0), PosIdx::new(1, 0), PosIdx::new(1, 1)]));
+    }
+}
@@ -0,0 +1,360 @@
+#![cfg(feature = "std")]
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use crate::error::Error;
+use crate::input::normalize;
+
+/// A puzzle solution for a single day.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input;
+
+    /// The example inputs and their expected answers.
+    const EXAMPLES: &'static [Example];
+
+    /// Parses the puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    /// Solves part 1, or returns an [`Error`] if the input has no answer.
+    fn part1(input: &Self::Input) -> Result<impl Display, Error>;
+
+    /// Solves part 2, or returns an [`Error`] if the input has no answer.
+    fn part2(input: &Self::Input) -> Result<impl Display, Error>;
+}
+
+/// The expected answer to a [`Part`] of an [`Example`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Expected {
+    /// The part is solved and should produce this answer.
+    Answer(&'static str),
+
+    /// The part is not implemented yet, but should eventually produce this answer.
+    Todo(&'static str),
+
+    /// The answer is not known.
+    Unknown,
+}
+
+/// An example puzzle input with its expected answers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Expected,
+    pub part2: Expected,
+}
+
+impl Example {
+    /// Returns the expected answer to a [`Part`].
+    #[inline]
+    pub fn expected(&self, part: Part) -> Expected {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+/// An error returned when parsing a [`Part`] fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsePartError;
+
+/// Represents a puzzle part.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(ParsePartError),
+        }
+    }
+}
+
+impl Part {
+    /// Returns all the parts.
+    #[inline]
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+/// The answer to a single [`Part`], or the [`Error`] explaining why there is none.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: Result<String, Error>,
+    pub elapsed: Duration,
+}
+
+impl Display for Answer {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Ok(value) => write!(f, "{}: {} ({:?})", self.part, value, self.elapsed),
+            Err(e) => write!(f, "{}: {} ({:?})", self.part, e, self.elapsed),
+        }
+    }
+}
+
+/// The result of running a [`Solution`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Display for Run {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "parse: {:?}", self.parse_elapsed)?;
+        for answer in &self.answers {
+            write!(f, "\n{}", answer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Run {
+    /// Returns `true` if every part has an answer.
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.answers.iter().all(|x| x.value.is_ok())
+    }
+
+    /// Returns the total time spent parsing and solving.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.parse_elapsed + self.answers.iter().map(|x| x.elapsed).sum::<Duration>()
+    }
+}
+
+/// Runs a [`Solution`] on an input, timing each step. Runs both parts if `part` is [`None`].
+pub fn run<S: Solution>(input: &str, part: Option<Part>) -> Result<Run, Error> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let answers = Part::all()
+        .into_iter()
+        .filter(|&p| part.is_none_or(|x| x == p))
+        .map(|p| {
+            let start = Instant::now();
+            let value = match p {
+                Part::One => S::part1(&parsed).map(|x| x.to_string()),
+                Part::Two => S::part2(&parsed).map(|x| x.to_string()),
+            };
+            Answer { part: p, value, elapsed: start.elapsed() }
+        })
+        .collect();
+    Ok(Run { parse_elapsed, answers })
+}
+
+/// The outcome of checking a [`Part`] against its [`Expected`] answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Unimplemented,
+    Unknown,
+    Invalid(Error),
+}
+
+impl Display for Status {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => write!(f, "fail (expected {}, got {})", expected, actual),
+            Status::Unimplemented => write!(f, "unimplemented"),
+            Status::Unknown => write!(f, "unknown"),
+            Status::Invalid(e) => write!(f, "invalid input ({})", e),
+        }
+    }
+}
+
+impl Status {
+    /// Returns the [`Status`] of an answer.
+    pub fn of(expected: Expected, actual: &str) -> Self {
+        match expected {
+            Expected::Answer(x) | Expected::Todo(x) if x == actual => Status::Pass,
+            Expected::Answer(x) => Status::Fail { expected: String::from(x), actual: String::from(actual) },
+            Expected::Todo(_) => Status::Unimplemented,
+            Expected::Unknown => Status::Unknown,
+        }
+    }
+}
+
+/// The result of checking one [`Part`] of an [`Example`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Check {
+    pub example: usize,
+    pub part: Part,
+    pub status: Status,
+}
+
+impl Display for Check {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "example {} {}: {}", self.example + 1, self.part, self.status)
+    }
+}
+
+/// Runs a [`Solution`] on all its examples, checking each answer. Example inputs are normalized like loaded inputs.
+pub fn check<S: Solution>() -> Vec<Check> {
+    let mut result = Vec::with_capacity(S::EXAMPLES.len() * 2);
+    for (idx, example) in S::EXAMPLES.iter().enumerate() {
+        match run::<S>(&normalize(example.input), None) {
+            Ok(run) => result.extend(run.answers.into_iter().map(|answer| Check {
+                example: idx,
+                part: answer.part,
+                status: match answer.value {
+                    Ok(value) => Status::of(example.expected(answer.part), &value),
+                    Err(e) => Status::Invalid(e),
+                },
+            })),
+            Err(e) => {
+                result.extend(Part::all().map(|part| Check { example: idx, part, status: Status::Invalid(e.clone()) }))
+            },
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Reason;
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+
+        const EXAMPLES: &'static [Example] = &[
+            Example { input: "1,2,3", part1: Expected::Answer("6"), part2: Expected::Answer("6") },
+            Example { input: "2,3", part1: Expected::Answer("4"), part2: Expected::Todo("6") },
+            Example { input: "4,5", part1: Expected::Unknown, part2: Expected::Todo("100") },
+            Example { input: "", part1: Expected::Answer("0"), part2: Expected::Answer("1") },
+            Example { input: "65536,65536", part1: Expected::Answer("131072"), part2: Expected::Unknown },
+        ];
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            if input.is_empty() {
+                return Err(Error::parse(input, Reason::Empty));
+            }
+            input.split(',').map(|x| Ok(x.parse()?)).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+            Ok(input.iter().sum::<i32>())
+        }
+
+        fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+            input.iter().try_fold(1i32, |acc, &x| acc.checked_mul(x)).ok_or(Error::NoAnswer("product overflows"))
+        }
+    }
+
+    #[test]
+    fn test_part_display() {
+        assert_eq!(format!("{}", Part::One), "part 1");
+        assert_eq!(format!("{}", Part::Two), "part 2");
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!(Part::from_str("1"), Ok(Part::One));
+        assert_eq!(Part::from_str("2"), Ok(Part::Two));
+        assert_eq!(Part::from_str("3"), Err(ParsePartError));
+    }
+
+    #[test]
+    fn test_answer_display() {
+        let sut = Answer { part: Part::Two, value: Ok(String::from("42")), elapsed: Duration::from_millis(3) };
+        assert_eq!(format!("{}", sut), "part 2: 42 (3ms)");
+
+        let sut = Answer { part: Part::One, value: Err(Error::NoAnswer("no path")), elapsed: Duration::from_millis(3) };
+        assert_eq!(format!("{}", sut), "part 1: no answer: no path (3ms)");
+    }
+
+    #[test]
+    fn test_run() {
+        let sut = run::<Sum>("1,2,3,4", None).unwrap();
+        let values: Vec<_> = sut.answers.iter().map(|x| (x.part, x.value.as_deref())).collect();
+        assert_eq!(values, vec![(Part::One, Ok("10")), (Part::Two, Ok("24"))]);
+        assert!(sut.is_ok());
+
+        let sut = run::<Sum>("1,2,3,4", Some(Part::Two)).unwrap();
+        let values: Vec<_> = sut.answers.iter().map(|x| (x.part, x.value.as_deref())).collect();
+        assert_eq!(values, vec![(Part::Two, Ok("24"))]);
+        assert!(sut.elapsed() >= sut.parse_elapsed);
+
+        let sut = run::<Sum>("65536,65536", None).unwrap();
+        let values: Vec<_> = sut.answers.iter().map(|x| (x.part, x.value.as_deref())).collect();
+        assert_eq!(values, vec![(Part::One, Ok("131072")), (Part::Two, Err(&Error::NoAnswer("product overflows")))]);
+        assert!(!sut.is_ok());
+
+        let sut = run::<Sum>("1,x", None);
+        assert!(sut.is_err());
+    }
+
+    #[test]
+    fn test_status_of() {
+        assert_eq!(Status::of(Expected::Answer("1"), "1"), Status::Pass);
+        assert_eq!(
+            Status::of(Expected::Answer("1"), "2"),
+            Status::Fail { expected: String::from("1"), actual: String::from("2") }
+        );
+        assert_eq!(Status::of(Expected::Todo("1"), "1"), Status::Pass);
+        assert_eq!(Status::of(Expected::Todo("1"), "0"), Status::Unimplemented);
+        assert_eq!(Status::of(Expected::Unknown, "0"), Status::Unknown);
+    }
+
+    #[test]
+    fn test_check_display() {
+        let sut = Check { example: 0, part: Part::One, status: Status::Pass };
+        assert_eq!(format!("{}", sut), "example 1 part 1: pass");
+
+        let sut = Check {
+            example: 1,
+            part: Part::Two,
+            status: Status::Fail { expected: String::from("9021"), actual: String::from("0") },
+        };
+        assert_eq!(format!("{}", sut), "example 2 part 2: fail (expected 9021, got 0)");
+    }
+
+    #[test]
+    fn test_check() {
+        let sut: Vec<_> = check::<Sum>().into_iter().map(|x| (x.example, x.part, x.status)).collect();
+        assert_eq!(sut, vec![
+            (0, Part::One, Status::Pass),
+            (0, Part::Two, Status::Pass),
+            (1, Part::One, Status::Fail { expected: String::from("4"), actual: String::from("5") }),
+            (1, Part::Two, Status::Pass),
+            (2, Part::One, Status::Unknown),
+            (2, Part::Two, Status::Unimplemented),
+            (3, Part::One, Status::Invalid(Error::parse("", Reason::Empty))),
+            (3, Part::Two, Status::Invalid(Error::parse("", Reason::Empty))),
+            (4, Part::One, Status::Pass),
+            (4, Part::Two, Status::Invalid(Error::NoAnswer("product overflows"))),
+        ]);
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+use aocrs::solution::Part;
+
+pub const USAGE: &str = "usage: aoc [--day <day>] [--part <1|2>] [--input <file>] [--check]";
+
+/// An error returned when parsing command line arguments fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ArgsError {
+    Help,
+    MissingValue(String),
+    InvalidValue(String, String),
+    InputWithoutDay,
+    Unknown(String),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgsError::Help => write!(f, "{}", USAGE),
+            ArgsError::MissingValue(flag) => write!(f, "missing value for {}", flag),
+            ArgsError::InvalidValue(flag, value) => write!(f, "invalid value for {}: {}", flag, value),
+            ArgsError::InputWithoutDay => write!(f, "--input requires --day"),
+            ArgsError::Unknown(arg) => write!(f, "unknown argument: {}", arg),
+        }
+    }
+}
+
+/// The parsed command line arguments.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Args {
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: Option<String>,
+    pub check: bool,
+}
+
+impl Args {
+    /// Parses command line arguments, excluding the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut result = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                "-c" | "--check" => result.check = true,
+                "-d" | "--day" => {
+                    let value = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                    result.day = Some(value.parse().map_err(|_| ArgsError::InvalidValue(arg, value))?);
+                }
+                "-p" | "--part" => {
+                    let value = args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?;
+                    result.part = Some(value.parse().map_err(|_| ArgsError::InvalidValue(arg, value))?);
+                }
+                "-i" | "--input" => {
+                    result.input = Some(args.next().ok_or_else(|| ArgsError::MissingValue(arg.clone()))?);
+                }
+                _ => return Err(ArgsError::Unknown(arg)),
+            }
+        }
+
+        if result.input.is_some() && result.day.is_none() {
+            return Err(ArgsError::InputWithoutDay);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let sut = Args::parse(args("")).unwrap();
+        assert_eq!(sut, Args::default());
+
+        let sut = Args::parse(args("--day 6 --part 2 --input other.txt")).unwrap();
+        let input = Some(String::from("other.txt"));
+        assert_eq!(sut, Args { day: Some(6), part: Some(Part::Two), input, check: false });
+
+        let sut = Args::parse(args("-d 6 -p 1")).unwrap();
+        assert_eq!(sut, Args { day: Some(6), part: Some(Part::One), input: None, check: false });
+
+        let sut = Args::parse(args("--check")).unwrap();
+        assert_eq!(sut, Args { check: true, ..Args::default() });
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Args::parse(args("--help")), Err(ArgsError::Help));
+        assert_eq!(Args::parse(args("--day")), Err(ArgsError::MissingValue(String::from("--day"))));
+        assert_eq!(
+            Args::parse(args("--part 3")),
+            Err(ArgsError::InvalidValue(String::from("--part"), String::from("3")))
+        );
+        assert_eq!(Args::parse(args("--input a.txt")), Err(ArgsError::InputWithoutDay));
+        assert_eq!(Args::parse(args("--year 2024")), Err(ArgsError::Unknown(String::from("--year"))));
+    }
+}
@@ -0,0 +1,96 @@
+use aocrs::error::Error;
+use aocrs::solution::{check, run, Check, Part, Run};
+use day01::Day01;
+use day02::Day02;
+use day03::Day03;
+use day04::Day04;
+use day05::Day05;
+use day06::Day06;
+use day07::Day07;
+use day08::Day08;
+use day09::Day09;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use day13::Day13;
+use day14::Day14;
+use day15::Day15;
+use day16::Day16;
+use day17::Day17;
+use day18::Day18;
+use day19::Day19;
+use day20::Day20;
+use day21::Day21;
+
+/// A registered day.
+#[derive(Clone, Copy, Debug)]
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&str, Option<Part>) -> Result<Run, Error>,
+    pub check: fn() -> Vec<Check>,
+}
+
+impl Day {
+    /// Returns the path of the default input file.
+    #[inline]
+    pub fn input_path(&self) -> String {
+        format!("./day{:02}/input.txt", self.day)
+    }
+}
+
+/// All the registered days, in order.
+pub const DAYS: [Day; 21] = [
+    Day { day: 1, run: run::<Day01>, check: check::<Day01> },
+    Day { day: 2, run: run::<Day02>, check: check::<Day02> },
+    Day { day: 3, run: run::<Day03>, check: check::<Day03> },
+    Day { day: 4, run: run::<Day04>, check: check::<Day04> },
+    Day { day: 5, run: run::<Day05>, check: check::<Day05> },
+    Day { day: 6, run: run::<Day06>, check: check::<Day06> },
+    Day { day: 7, run: run::<Day07>, check: check::<Day07> },
+    Day { day: 8, run: run::<Day08>, check: check::<Day08> },
+    Day { day: 9, run: run::<Day09>, check: check::<Day09> },
+    Day { day: 10, run: run::<Day10>, check: check::<Day10> },
+    Day { day: 11, run: run::<Day11>, check: check::<Day11> },
+    Day { day: 12, run: run::<Day12>, check: check::<Day12> },
+    Day { day: 13, run: run::<Day13>, check: check::<Day13> },
+    Day { day: 14, run: run::<Day14>, check: check::<Day14> },
+    Day { day: 15, run: run::<Day15>, check: check::<Day15> },
+    Day { day: 16, run: run::<Day16>, check: check::<Day16> },
+    Day { day: 17, run: run::<Day17>, check: check::<Day17> },
+    Day { day: 18, run: run::<Day18>, check: check::<Day18> },
+    Day { day: 19, run: run::<Day19>, check: check::<Day19> },
+    Day { day: 20, run: run::<Day20>, check: check::<Day20> },
+    Day { day: 21, run: run::<Day21>, check: check::<Day21> },
+];
+
+/// Finds a registered day.
+#[inline]
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|x| x.day == day)
+}
+
+#[cfg(test)]
+mod test {
+    use aocrs::solution::Status;
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let failed: Vec<_> = DAYS
+            .iter()
+            .flat_map(|day| (day.check)().into_iter().map(move |x| (day.day, x)))
+            .filter(|(_, x)| matches!(x.status, Status::Fail { .. } | Status::Invalid(_)))
+            .map(|(day, x)| format!("day {:02} {}", day, x))
+            .collect();
+        assert!(failed.is_empty(), "{}", failed.join("\n"));
+    }
+
+    #[test]
+    fn test_examples_declared() {
+        for day in DAYS {
+            let checks = (day.check)();
+            assert!(!checks.is_empty(), "day {:02} has no examples", day.day);
+            assert!(checks.iter().any(|x| x.status != Status::Unknown), "day {:02} has no known answers", day.day);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::env;
+use std::process::ExitCode;
+use std::time::Duration;
+use aocrs::input::get_text;
+use aocrs::solution::Status;
+use crate::args::Args;
+use crate::days::{Day, DAYS};
+
+mod args;
+mod days;
+
+fn run_day(day: &Day, args: &Args) -> Result<Duration, String> {
+    let path = args.input.clone().unwrap_or_else(|| day.input_path());
+    let input = get_text(&path).map_err(|e| format!("failed to read {}: {}", path, e))?;
+    let run = (day.run)(&input, args.part).map_err(|e| format!("failed to parse {}: {}", path, e))?;
+
+    println!("day {:02}", day.day);
+    for line in run.to_string().lines() {
+        println!("  {}", line);
+    }
+    if !run.is_ok() {
+        return Err(format!("failed to solve {}", path));
+    }
+    Ok(run.elapsed())
+}
+
+fn check_day(day: &Day) -> bool {
+    println!("day {:02}", day.day);
+    let mut passed = true;
+    for check in (day.check)() {
+        println!("  {}", check);
+        passed &= !matches!(check.status, Status::Fail { .. } | Status::Invalid(_));
+    }
+    passed
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let days: Vec<_> = match args.day {
+        Some(day) => match days::find(day) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("day {} is not registered", day);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => DAYS.iter().collect(),
+    };
+
+    if args.check {
+        let failed = days.into_iter().filter(|day| !check_day(day)).count();
+        return if failed == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+    }
+
+    let mut total = Duration::ZERO;
+    for day in days {
+        match run_day(day, &args) {
+            Ok(elapsed) => total += elapsed,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    println!("total: {:?}", total);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use aocrs::error::{Error, Reason};
+use aocrs::input::{ints, parse_lines};
+use aocrs::solution::{Example, Expected, Solution};
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("11"),
+        part2: Expected::Answer("31"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let (mut first, mut second): (Vec<_>, Vec<_>) = parse_lines(input, parse_line)?.into_iter().unzip();
+
+        first.sort();
+        second.sort();
+        Ok((first, second))
+    }
+
+    fn part1((first, second): &Self::Input) -> Result<impl Display, Error> {
+        let mut result = 0;
+        for idx in 0..first.len() {
+            result += (first[idx] - second[idx]).abs();
+        }
+        Ok(result)
+    }
+
+    fn part2((first, second): &Self::Input) -> Result<impl Display, Error> {
+        let mut result = 0;
+        let occurrences: HashMap<_, _> = second
+            .iter()
+            .into_grouping_map_by(|&&v| v)
+            .fold(0, |acc, _, _| acc + 1);
+        for num in first {
+            let occurrence = occurrences.get(num);
+            result += match occurrence {
+                Some(v) => num * v,
+                None => 0,
+            };
+        }
+        Ok(result)
+    }
+}
+
+fn parse_line(line: &str) -> Result<(i32, i32), Error> {
+    match ints(line)?[..] {
+        [left, right] => Ok((left, right)),
+        _ => Err(Error::parse(line, Reason::Invalid("pair of location IDs"))),
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::report::Report;
+
+pub mod report;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Report>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("2"),
+        part2: Expected::Answer("4"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| Report::from_str(line).map_err(|e| e.at_line(idx + 1)))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input
+            .iter()
+            .filter(|&report| report.is_safe())
+            .count())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input
+            .iter()
+            .filter(|&report| report.is_dampen_safe())
+            .count())
+    }
+}
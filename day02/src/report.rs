@@ -1,17 +1,18 @@
 use std::collections::HashSet;
 use std::str::FromStr;
+use aocrs::error::Error;
 
 #[derive(Clone, Debug)]
 pub struct Report(Vec<i32>);
 
 impl FromStr for Report {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let levels: Vec<_> = s
+        let levels = s
             .split_whitespace()
-            .filter_map(|s| s.parse::<i32>().ok())
-            .collect();
+            .map(|x| i32::from_str(x).map_err(|e| Error::from(e).with_text(x)))
+            .collect::<Result<_, _>>()?;
         Ok(Report(levels))
     }
 }
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+use std::sync::LazyLock;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use regex::Regex;
+
+static RGX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"mul\((\d+),(\d+)\)").unwrap()
+});
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("161"),
+        part2: Expected::Answer("48"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.lines().collect::<Vec<_>>().join(""))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(get_mul_sum(&RGX, input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(get_conditional_mul_sum(&RGX, input))
+    }
+}
+
+fn get_mul_sum(re: &Regex, input: &str) -> i32 {
+    let mut result = 0;
+    for (_, [first, second]) in re.captures_iter(input).map(|c| c.extract()) {
+        result += first.parse::<i32>().unwrap() * second.parse::<i32>().unwrap();
+    }
+    result
+}
+
+fn get_conditional_mul_sum(re: &Regex, input: &str) -> i32 {
+    let do_idxes: Vec<_> = input.match_indices("do()").map(|(idx, _)| idx).collect();
+    let dont_idxes: Vec<_> = input.match_indices("don't()").map(|(idx, _)| idx).collect();
+    let mut result = 0;
+    for cap in re.captures_iter(input) {
+        let (_, [first, second]) = cap.extract();
+        let pos = cap.get(0).unwrap().start();
+
+        let do_idx = do_idxes.iter().filter(|&x| x < &pos).max();
+        let dont_idx = dont_idxes.iter().filter(|&x| x < &pos).max();
+        if do_idx.is_none() && dont_idx.is_some() {
+            // we haven't encountered any do() but has a don't()
+            continue;
+        }
+        if do_idx.is_some() && dont_idx.is_some() && do_idx.unwrap() < dont_idx.unwrap() {
+            // when a do() appears before a don't()
+            continue;
+        }
+
+        result += first.parse::<i32>().unwrap() * second.parse::<i32>().unwrap();
+    }
+    result
+}
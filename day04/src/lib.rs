@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::word_search::WordSearch;
+
+pub mod word_search;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = WordSearch;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("18"),
+        part2: Expected::Answer("9"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        WordSearch::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.count_xmas())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.count_diag_xmas())
+    }
+}
@@ -1,11 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::word_search::WordSearch;
-
-mod word_search;
+use aocrs::solution::Solution;
+use day04::Day04;
 
 fn main() {
-    let search = WordSearch::from_str(&get_text("./day04/input.txt").unwrap()).unwrap();
-    println!("part 1: {}", search.count_xmas());            // 18
-    println!("part 2: {}", search.count_diag_xmas());       // 9
+    let input = Day04::parse(&get_text("./day04/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day04::part1(&input).unwrap());
+    println!("part 2: {}", Day04::part2(&input).unwrap());
 }
@@ -1,13 +1,13 @@
 use std::str::FromStr;
+use aocrs::error::Error;
 use aocrs::geo::direction::Direction;
-use aocrs::geo::grid::{CharGrid, ParseGridError};
-use aocrs::geo::pos::PosIdx;
+use aocrs::geo::grid::CharGrid;
 
 #[derive(Clone, Debug)]
 pub struct WordSearch(CharGrid);
 
 impl FromStr for WordSearch {
-    type Err = ParseGridError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self(CharGrid::from_str(s)?))
@@ -15,9 +15,16 @@ impl FromStr for WordSearch {
 }
 
 impl WordSearch {
-    #[inline]
     pub fn count_xmas(&self) -> usize {
-        self.0.area().into_iter().filter(|&x| self.0[x] == b'X').map(|x| self.count_at_pos(&x)).sum()
+        let rows = self.0.rows().map(|x| x.to_vec());
+        let columns = self.0.columns().map(|x| x.copied().collect());
+        let diagonals = self.0.diagonals().map(|x| x.copied().collect());
+        let anti_diagonals = self.0.anti_diagonals().map(|x| x.copied().collect());
+        rows.chain(columns)
+            .chain(diagonals)
+            .chain(anti_diagonals)
+            .map(|line: Vec<u8>| line.windows(4).filter(|&x| x == b"XMAS" || x == b"SAMX").count())
+            .sum()
     }
 
     pub fn count_diag_xmas(&self) -> usize {
@@ -41,18 +48,4 @@ impl WordSearch {
         }
         result
     }
-
-    #[inline]
-    fn count_at_pos(&self, pos: &PosIdx) -> usize {
-        Direction::all().iter().filter(|&&x| self.is_direction_xmas(pos, x)).count()
-    }
-
-    fn is_direction_xmas(&self, pos: &PosIdx, direction: Direction) -> bool {
-        let valid: Vec<_> = [
-            pos.checked_dest(1, direction),
-            pos.checked_dest(2, direction),
-            pos.checked_dest(3, direction),
-        ].iter().filter_map(|&x| x).collect();
-        self.0.extract_string(valid).unwrap() == "MAS"
-    }
 }
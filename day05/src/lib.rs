@@ -0,0 +1,54 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::page_order::PageOrderMap;
+use crate::update::Update;
+
+pub mod page_order;
+pub mod update;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (PageOrderMap, Vec<Update>);
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("143"),
+        part2: Expected::Answer("123"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut page_order = PageOrderMap::default();
+        let mut updates: Vec<Update> = Vec::with_capacity(500);
+        for (idx, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.contains('|') {
+                page_order.add(line).map_err(|e| e.at_line(idx + 1))?;
+            } else {
+                updates.push(Update::from_str(line).map_err(|e| e.at_line(idx + 1))?);
+            }
+        }
+        Ok((page_order, updates))
+    }
+
+    fn part1((page_order, updates): &Self::Input) -> Result<impl Display, Error> {
+        Ok(updates
+            .iter()
+            .filter(|x| x.is_valid(page_order))
+            .map(|x| x.middle())
+            .sum::<i32>())
+    }
+
+    fn part2((page_order, updates): &Self::Input) -> Result<impl Display, Error> {
+        Ok(updates
+            .iter()
+            .filter(|x| !x.is_valid(page_order))
+            .map(|x| x.fix(page_order).middle())
+            .sum::<i32>())
+    }
+}
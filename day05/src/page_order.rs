@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
 
 /// A map between page numbers and all the page numbers.
 #[derive(Clone, Debug)]
@@ -21,13 +22,16 @@ impl Default for PageOrderMap {
 }
 
 impl PageOrderMap {
-    pub fn add(&mut self, item: &str) {
+    pub fn add(&mut self, item: &str) -> Result<(), Error> {
         // item = before|after
-        let splits: Vec<_> = item.split("|").collect();
-        let before = i32::from_str(splits[0]).unwrap();
-        let after = i32::from_str(splits[1]).unwrap();
+        let (before, after) = item
+            .split_once("|")
+            .ok_or_else(|| Error::parse(item, Reason::Missing("separator `|`")))?;
+        let before = i32::from_str(before).map_err(|e| Error::from(e).with_text(item))?;
+        let after = i32::from_str(after).map_err(|e| Error::from(e).with_text(item))?;
         self.after_map.entry(before).or_default().insert(after);
         self.before_map.entry(after).or_default().insert(before);
+        Ok(())
     }
 
     pub fn is_valid(&self, before: i32, after: i32) -> bool {
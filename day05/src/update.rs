@@ -1,14 +1,18 @@
 use std::str::FromStr;
+use aocrs::error::Error;
 use crate::page_order::PageOrderMap;
 
 #[derive(Clone, Debug)]
 pub struct Update(Vec<i32>);
 
 impl FromStr for Update {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let nums: Vec<_> = s.split(",").filter_map(|n| n.parse::<i32>().ok()).collect();
+        let nums = s
+            .split(",")
+            .map(|n| i32::from_str(n).map_err(|e| Error::from(e).with_text(s)))
+            .collect::<Result<_, _>>()?;
         Ok(Self(nums))
     }
 }
@@ -1,11 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::map::Map;
-
-mod map;
+use aocrs::solution::Solution;
+use day06::Day06;
 
 fn main() {
-    let map =     Map::from_str(&get_text("./day06/input.txt").unwrap()).unwrap();
-    println!("part 1: {}", map.get_path().len());       // 41
-    println!("part 2: {}", map.count_blocks());         // 6
+    let input = Day06::parse(&get_text("./day06/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day06::part1(&input).unwrap());
+    println!("part 2: {}", Day06::part2(&input).unwrap());
 }
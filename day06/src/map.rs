@@ -1,9 +1,10 @@
-use std::collections::HashSet;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use aocrs::error::{Error, Reason};
 use aocrs::geo::area::Area;
+use aocrs::geo::bit_grid::{BitGrid, DirectionalBitGrid};
 use aocrs::geo::direction::{Direction, DirectionalPos};
-use aocrs::geo::grid::{CharGrid, ParseGridError};
+use aocrs::geo::grid::CharGrid;
 use aocrs::geo::pos::PosIdx;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
@@ -14,19 +15,19 @@ pub struct Map {
 }
 
 impl FromStr for Map {
-    type Err = ParseGridError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let grid = CharGrid::from_str(s)?;
-        let start = grid.find(b'^').unwrap();
+        let start = grid.find(b'^').ok_or_else(|| Error::parse("", Reason::Missing("guard `^`")))?;
         Ok(Self { grid, start })
     }
 }
 
 impl Map {
-    pub fn get_path(&self) -> HashSet<PosIdx> {
-        let mut visited: HashSet<PosIdx> = HashSet::with_capacity(7500);
-        self.iterate_path(|x| { visited.insert(x.pos); });
+    pub fn get_path(&self) -> BitGrid {
+        let mut visited = BitGrid::for_grid(&self.grid);
+        self.iterate_path(|x| { visited.insert(&x.pos); });
         visited
     }
 
@@ -34,7 +35,7 @@ impl Map {
         let count = AtomicUsize::new(0);
         let area = self.grid.area();
         let path = self.get_path();
-        path.into_par_iter().for_each(|pos| {
+        path.iter().collect::<Vec<_>>().into_par_iter().for_each(|pos| {
             if pos == self.start {
                 return;
             }
@@ -47,17 +48,17 @@ impl Map {
     }
 
     fn is_loop(&self, block: PosIdx, area: Area<usize>) -> bool {
-        let mut dpos = DirectionalPos::new(self.start, Direction::Down);
-        let mut visited: HashSet<DirectionalPos<usize>> = HashSet::with_capacity(7500);
+        let mut dpos = DirectionalPos::new(self.start, Direction::Up);
+        let mut visited = DirectionalBitGrid::for_grid(&self.grid);
         while !area.on_boundary(&dpos.pos) {
-            let next = dpos.next(1);
+            let next = dpos.screen_next(1);
             if next.pos == block || self.grid[next.pos] == b'#' {
-                dpos = dpos.update_direction(dpos.direction.left());
+                dpos = dpos.update_direction(dpos.direction.right());
             } else {
                 dpos = next;
             }
 
-            if !visited.insert(dpos) {
+            if !visited.insert(&dpos) {
                 return true;
             }
         }
@@ -65,15 +66,14 @@ impl Map {
     }
 
     fn iterate_path(&self, mut on_each: impl FnMut(DirectionalPos<usize>)) {
-        // grid is reversed, inverse direction
-        let mut dpos = DirectionalPos::new(self.start, Direction::Down);
+        let mut dpos = DirectionalPos::new(self.start, Direction::Up);
         on_each(dpos);
 
         let area = self.grid.area();
         while !area.on_boundary(&dpos.pos) {
-            let next = dpos.next(1);
+            let next = dpos.screen_next(1);
             if self.grid[next.pos] == b'#' {
-                dpos = dpos.update_direction(dpos.direction.left());
+                dpos = dpos.update_direction(dpos.direction.right());
             } else {
                 dpos = next;
             }
@@ -1,5 +1,6 @@
-use std::num::ParseIntError;
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
+use aocrs::input::words;
 use aocrs::math::digits;
 use itertools::Itertools;
 
@@ -10,15 +11,16 @@ pub struct Equation {
 }
 
 impl FromStr for Equation {
-    type Err = ParseIntError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splits: Vec<&str> = s.split(": ").collect();
-        let ans: i64 = i64::from_str(splits[0])?;
-        let nums: Vec<_> = splits[1]
-            .split(" ")
-            .filter_map(|n| i64::from_str(n).ok())
-            .collect();
+        let (ans, nums) = s.split_once(':').ok_or_else(|| Error::parse(s, Reason::Missing("separator `:`")))?;
+        let column = ans.len() + 2;
+        let ans = i64::from_str(ans.trim()).map_err(|e| Error::from(e).with_text(s).at_column(1))?;
+        let nums: Vec<i64> = words(nums).map_err(|e| e.offset_column(s, column))?;
+        if nums.is_empty() {
+            return Err(Error::parse(s, Reason::Missing("numbers")).at_column(column));
+        }
         Ok(Self { ans, nums })
     }
 }
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::input::parse_lines;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::equation::Equation;
+
+pub mod equation;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("3749"),
+        part2: Expected::Answer("11387"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, Equation::from_str)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input
+            .iter()
+            .map(|x| x.get_ans(b"*+"))
+            .sum::<i64>())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input
+            .iter()
+            .map(|x| x.get_ans(b"*+|"))
+            .sum::<i64>())
+    }
+}
@@ -1,29 +1,9 @@
-use std::str::FromStr;
-use aocrs::input::get_lines;
-use crate::equation::Equation;
-
-mod equation;
-
-fn get_input(filename: &str) -> Vec<Equation> {
-    let mut result: Vec<Equation> = Vec::with_capacity(1000);
-    let lines = get_lines(filename).unwrap();
-    for line in lines.map_while(Result::ok) {
-        result.push(Equation::from_str(&line).unwrap());
-    }
-    result
-}
+use aocrs::input::get_text;
+use aocrs::solution::Solution;
+use day07::Day07;
 
 fn main() {
-    let equations = get_input("./day07/input.txt");
-    let passed_sum: i64 = equations
-        .iter()
-        .map(|x| x.get_ans(b"*+"))
-        .sum();
-    println!("part 1: {}", passed_sum);     // 3749
-
-    let passed_sum: i64 = equations
-        .iter()
-        .map(|x| x.get_ans(b"*+|"))
-        .sum();
-    println!("part 2: {}", passed_sum);     // 11387
+    let input = Day07::parse(&get_text("./day07/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day07::part1(&input).unwrap());
+    println!("part 2: {}", Day07::part2(&input).unwrap());
 }
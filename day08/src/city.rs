@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
-use aocrs::geo::grid::{CharGrid, ParseGridError};
+use aocrs::error::Error;
+use aocrs::geo::grid::CharGrid;
 use aocrs::geo::pos::PosIdx;
 
 #[derive(Clone, Debug)]
@@ -10,7 +11,7 @@ pub struct City {
 }
 
 impl FromStr for City {
-    type Err = ParseGridError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let grid = CharGrid::from_str(s)?;
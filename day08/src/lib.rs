@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::city::City;
+
+pub mod city;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = City;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("14"),
+        part2: Expected::Answer("34"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        City::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.get_all_antinodes().len())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.get_all_harmonic_antinodes().len())
+    }
+}
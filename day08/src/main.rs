@@ -1,11 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::city::City;
-
-mod city;
+use aocrs::solution::Solution;
+use day08::Day08;
 
 fn main() {
-    let city = City::from_str(&get_text("./day08/input.txt").unwrap()).unwrap();
-    println!("part 1: {}", city.get_all_antinodes().len());             // 14
-    println!("part 2: {}", city.get_all_harmonic_antinodes().len());    // 34
+    let input = Day08::parse(&get_text("./day08/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day08::part1(&input).unwrap());
+    println!("part 2: {}", Day08::part2(&input).unwrap());
 }
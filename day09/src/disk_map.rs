@@ -1,104 +1,244 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
 
-const EMPTY: usize = usize::MAX;
+/// The longest span a single digit of the disk map can describe.
+const MAX_SPAN: usize = 9;
+
+/// A run of contiguous blocks on the disk.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Span {
+    /// Returns the checksum contribution of a file with an ID occupying this [`Span`].
+    #[inline]
+    fn checksum(&self, id: usize) -> usize {
+        id * (self.len * self.start + self.len * self.len.saturating_sub(1) / 2)
+    }
+}
+
+/// How files are moved to the left to compact the disk.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CompactionStrategy {
+    /// Moves single blocks from the end of the disk into the leftmost free blocks, splitting files.
+    Block,
+    /// Moves whole files, from the highest ID down, into the leftmost free span which fits them.
+    FirstFit,
+    /// Moves whole files, from the highest ID down, into the smallest free span on their left which fits them.
+    BestFit,
+    /// Moves whole files, from the highest ID down, into the largest free span on their left.
+    WorstFit,
+}
+
+/// Which free span a whole file moves into.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Fit {
+    First,
+    Best,
+    Worst,
+}
+
+/// A run of blocks of a file moved during compaction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Move {
+    pub id: usize,
+    pub from: usize,
+    pub to: usize,
+    pub len: usize,
+}
+
+/// The result of compacting a [`DiskMap`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Compaction {
+    /// The moves made, in order.
+    pub moves: Vec<Move>,
+    /// The file ID and position of each run of file blocks after compaction, ordered by position.
+    pub files: Vec<(usize, Span)>,
+    size: usize,
+}
+
+impl Display for Compaction {
+    /// Renders the disk in the puzzle notation, such as `0099811188827773336446555566..............`, with one
+    /// character per block. Blocks show the last digit of their file ID, so IDs of 10 or more are ambiguous but stay
+    /// aligned with their positions.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut pos = 0;
+        for (id, span) in &self.files {
+            write!(f, "{}", ".".repeat(span.start - pos))?;
+            write!(f, "{}", (id % 10).to_string().repeat(span.len))?;
+            pos = span.start + span.len;
+        }
+        write!(f, "{}", ".".repeat(self.size - pos))
+    }
+}
+
+impl Compaction {
+    pub fn checksum(&self) -> usize {
+        self.files.iter().map(|(id, span)| span.checksum(*id)).sum()
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct DiskMap {
-    map: Vec<u8>,
-    expanded: Vec<usize>,
+    files: Vec<Span>,
+    spaces: Vec<Span>,
 }
 
 impl FromStr for DiskMap {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map: Vec<_> = s.chars().filter_map(|x| x.to_digit(10).map(|x| x as u8)).collect();
-        let mut expanded: Vec<usize> = Vec::with_capacity(100000);
-        for (idx, &num) in map.iter().enumerate() {
+        let mut files = Vec::with_capacity(s.len() / 2 + 1);
+        let mut spaces = Vec::with_capacity(s.len() / 2);
+        let mut start = 0;
+        for (idx, x) in s.trim_end().chars().enumerate() {
+            let Some(len) = x.to_digit(10) else {
+                return Err(Error::parse(&x.to_string(), Reason::Invalid("digit")).at_line(1).at_column(idx + 1));
+            };
+
+            let span = Span { start, len: len as usize };
             if idx % 2 == 0 {
-                expanded.extend(vec![idx / 2; num as usize]);
+                files.push(span);
             } else {
-                expanded.extend(vec![EMPTY; num as usize]);
+                spaces.push(span);
             }
+            start += span.len;
         }
-        Ok(Self { map, expanded })
+        Ok(Self { files, spaces })
     }
 }
 
 impl DiskMap {
-    pub fn bit_move_checksum(&self) -> usize {
-        let mut compacted = self.expanded.clone();
-        let mut space_idx = Self::next_space_idx(0, &compacted);
-        for file_idx in (0..compacted.len()).rev() {
-            if file_idx < space_idx {
-                break;
-            }
-            if compacted[file_idx] != EMPTY {
-                compacted.swap(space_idx, file_idx);
-                space_idx = Self::next_space_idx(space_idx, &compacted);
-            }
-        }
-        Self::checksum(&compacted)
+    pub fn compact(&self, strategy: CompactionStrategy) -> Compaction {
+        let (moves, mut files) = match strategy {
+            CompactionStrategy::Block => self.move_blocks(),
+            CompactionStrategy::FirstFit => self.move_files(Fit::First),
+            CompactionStrategy::BestFit => self.move_files(Fit::Best),
+            CompactionStrategy::WorstFit => self.move_files(Fit::Worst),
+        };
+        files.sort_unstable_by_key(|(_, span)| span.start);
+        let size = self.files.iter().chain(&self.spaces).map(|x| x.len).sum();
+        Compaction { moves, files, size }
     }
 
-    pub fn file_move_checksum(&self) -> usize {
-        let mut compacted = self.expanded.clone();
-        for file_idx in (0..self.map.len()).rev().step_by(2) {
-            let file_size = self.map[file_idx] as usize;
-            let file_start = self.start_chunk_idx(file_idx);
-            let next_empty = Self::next_empty_chunk(file_size, &compacted);
-            if next_empty < file_start {
-                Self::move_file(file_start, next_empty, &mut compacted);
+    fn move_blocks(&self) -> (Vec<Move>, Vec<(usize, Span)>) {
+        let mut remaining: Vec<_> = self.files.iter().map(|x| x.len).collect();
+        let mut last = self.files.len();
+        let mut moves: Vec<Move> = Vec::new();
+        'spaces: for space in &self.spaces {
+            for pos in space.start..space.start + space.len {
+                while last > 0 && remaining[last - 1] == 0 {
+                    last -= 1;
+                }
+                if last == 0 || self.files[last - 1].start + remaining[last - 1] <= pos + 1 {
+                    break 'spaces;
+                }
+
+                let id = last - 1;
+                remaining[id] -= 1;
+                let from = self.files[id].start + remaining[id];
+                match moves.last_mut() {
+                    Some(m) if m.id == id && m.to + m.len == pos && m.from == from + 1 => {
+                        m.from = from;
+                        m.len += 1;
+                    },
+                    _ => moves.push(Move { id, from, to: pos, len: 1 }),
+                }
             }
         }
-        Self::checksum(&compacted)
-    }
 
-    fn checksum(compacted: &[usize]) -> usize {
-        compacted
-            .iter()
-            .enumerate()
-            .filter(|(_, &num)| num != EMPTY)
-            .map(|(idx, &num)| idx * num)
-            .sum()
+        let unmoved = self.files.iter().zip(remaining).map(|(file, len)| Span { start: file.start, len });
+        let moved = moves.iter().map(|m| (m.id, Span { start: m.to, len: m.len }));
+        let files = unmoved.enumerate().filter(|(_, x)| x.len > 0).chain(moved).collect();
+        (moves, files)
     }
 
-    fn next_space_idx(idx: usize, nums: &[usize]) -> usize {
-        let mut result = idx;
-        while result < nums.len() && nums[result] != EMPTY {
-            result += 1;
+    fn move_files(&self, fit: Fit) -> (Vec<Move>, Vec<(usize, Span)>) {
+        let mut free: [BinaryHeap<Reverse<usize>>; MAX_SPAN + 1] = Default::default();
+        for space in self.spaces.iter().filter(|x| x.len > 0) {
+            free[space.len].push(Reverse(space.start));
         }
-        result
-    }
 
-    fn next_empty_chunk(size: usize, nums: &[usize]) -> usize {
-        let mut cur_size = 0;
-        for (idx, &num) in nums.iter().enumerate() {
-            if num == EMPTY {
-                cur_size += 1;
-            } else {
-                cur_size = 0;
-            }
+        let mut moves = Vec::new();
+        let mut files = Vec::with_capacity(self.files.len());
+        for (id, file) in self.files.iter().enumerate().rev().filter(|(_, x)| x.len > 0) {
+            let mut fits = (file.len..=MAX_SPAN)
+                .filter_map(|len| free[len].peek().map(|&Reverse(start)| (start, len)))
+                .filter(|&(start, _)| start < file.start);
+            let span = match fit {
+                Fit::First => fits.min(),
+                Fit::Best => fits.next(),
+                Fit::Worst => fits.next_back(),
+            };
 
-            if cur_size >= size {
-                return idx - cur_size + 1;
+            let Some((start, len)) = span else {
+                files.push((id, *file));
+                continue;
+            };
+
+            free[len].pop();
+            if len > file.len {
+                free[len - file.len].push(Reverse(start + file.len));
             }
+            moves.push(Move { id, from: file.start, to: start, len: file.len });
+            files.push((id, Span { start, len: file.len }));
         }
-        usize::MAX
+        (moves, files)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "2333133121414131402";
+
+    fn layout(disk: &str, strategy: CompactionStrategy) -> String {
+        DiskMap::from_str(disk).unwrap().compact(strategy).to_string()
     }
 
-    fn start_chunk_idx(&self, idx: usize) -> usize {
-        if idx == 0 { 0 } else { self.map[0..idx].iter().map(|&x| x as usize).sum() }
+    #[test]
+    fn test_block() {
+        let sut = DiskMap::from_str(EXAMPLE).unwrap().compact(CompactionStrategy::Block);
+        assert_eq!(sut.to_string(), "0099811188827773336446555566..............");
+        assert_eq!(sut.checksum(), 1928);
+        assert_eq!(sut.moves[0], Move { id: 9, from: 40, to: 2, len: 2 });
+        assert_eq!(sut.moves[1], Move { id: 8, from: 39, to: 4, len: 1 });
     }
 
-    fn move_file(file_idx: usize, space_idx: usize, nums: &mut [usize]) {
-        let file_id = nums[file_idx];
-        let mut cur_file_idx = file_idx;
-        let mut cur_space_idx = space_idx;
-        while cur_file_idx < nums.len() && nums[cur_file_idx] == file_id {
-            nums.swap(cur_file_idx, cur_space_idx);
-            cur_file_idx += 1;
-            cur_space_idx += 1;
-        }
+    #[test]
+    fn test_first_fit() {
+        let sut = DiskMap::from_str(EXAMPLE).unwrap().compact(CompactionStrategy::FirstFit);
+        assert_eq!(sut.to_string(), "00992111777.44.333....5555.6666.....8888..");
+        assert_eq!(sut.checksum(), 2858);
+        assert_eq!(sut.moves, vec![
+            Move { id: 9, from: 40, to: 2, len: 2 },
+            Move { id: 7, from: 32, to: 8, len: 3 },
+            Move { id: 4, from: 19, to: 12, len: 2 },
+            Move { id: 2, from: 11, to: 4, len: 1 },
+        ]);
+    }
+
+    #[test]
+    fn test_best_worst_fit() {
+        assert_eq!(layout("13122", CompactionStrategy::FirstFit), "0221.....");
+        assert_eq!(layout("13122", CompactionStrategy::BestFit), "01...22..");
+        assert_eq!(layout("13122", CompactionStrategy::WorstFit), "0221.....");
+
+        assert_eq!(layout("12132", CompactionStrategy::FirstFit), "0221.....");
+        assert_eq!(layout("12132", CompactionStrategy::BestFit), "0221.....");
+        assert_eq!(layout("12132", CompactionStrategy::WorstFit), "01..22...");
+    }
+
+    #[test]
+    fn test_layout_width() {
+        let sut = layout(&"1".repeat(24), CompactionStrategy::Block);
+        assert_eq!(sut, "011029384756............");
+        assert_eq!(sut.len(), 24);
     }
 }
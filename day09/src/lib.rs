@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::disk_map::{CompactionStrategy, DiskMap};
+
+pub mod disk_map;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = DiskMap;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("1928"),
+        part2: Expected::Answer("2858"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        DiskMap::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.compact(CompactionStrategy::Block).checksum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.compact(CompactionStrategy::FirstFit).checksum())
+    }
+}
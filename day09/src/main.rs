@@ -1,11 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::disk_map::DiskMap;
-
-mod disk_map;
+use aocrs::solution::Solution;
+use day09::Day09;
 
 fn main() {
-    let disk = DiskMap::from_str(&get_text("./day09/input.txt").unwrap()).unwrap();
-    println!("part 1: {}", disk.bit_move_checksum());       // 1928
-    println!("part 2: {}", disk.file_move_checksum());      // 2858
+    let input = Day09::parse(&get_text("./day09/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day09::part1(&input).unwrap());
+    println!("part 2: {}", Day09::part2(&input).unwrap());
 }
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::map::Map;
+
+pub mod map;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("36"),
+        part2: Expected::Answer("81"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Map::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.sum_scores())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.sum_ranks())
+    }
+}
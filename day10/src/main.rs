@@ -1,11 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::map::Map;
-
-mod map;
+use aocrs::solution::Solution;
+use day10::Day10;
 
 fn main() {
-    let map = Map::from_str(&get_text("./day10/input.txt").unwrap()).unwrap();
-    println!("part 1: {}", map.sum_scores());       // 36
-    println!("part 2: {}", map.sum_ranks());        // 81
+    let input = Day10::parse(&get_text("./day10/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day10::part1(&input).unwrap());
+    println!("part 2: {}", Day10::part2(&input).unwrap());
 }
@@ -1,8 +1,9 @@
-use std::collections::{HashSet, VecDeque};
 use std::str::FromStr;
+use aocrs::error::Error;
 use aocrs::geo::direction::Direction;
-use aocrs::geo::grid::{CharGrid, ParseGridError};
+use aocrs::geo::grid::CharGrid;
 use aocrs::geo::pos::PosIdx;
+use aocrs::search::bfs;
 
 const MAX_HEIGHT: u8 = b'9';
 
@@ -13,7 +14,7 @@ pub struct Map {
 }
 
 impl FromStr for Map {
-    type Err = ParseGridError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let grid = CharGrid::from_str(s)?;
@@ -32,22 +33,11 @@ impl Map {
     }
 
     fn score_at(&self, pos: &PosIdx) -> usize {
-        let mut score = 0;
-        let mut visited: HashSet<PosIdx> = HashSet::with_capacity(self.grid.size());
-        let mut queue: VecDeque<PosIdx> = VecDeque::from(vec![*pos]);
-
-        while let Some(pos) = queue.pop_front() {
-            if !visited.insert(pos) {
-                continue;
-            }
-
-            if self.grid[pos] == MAX_HEIGHT {
-                score += 1;
-            } else {
-                self.valid_neighbours(&pos).iter().for_each(|&x| queue.push_back(x));
-            }
-        }
-        score
+        bfs(*pos, |pos| self.valid_neighbours(pos), |_| false)
+            .costs
+            .into_keys()
+            .filter(|&pos| self.grid[pos] == MAX_HEIGHT)
+            .count()
     }
 
     fn rank_at(&self, pos: &PosIdx) -> usize {
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::stones::Stones;
+
+pub mod stones;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Stones;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("55312"),
+        part2: Expected::Answer("65601038650482"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Stones::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.count(25))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.count(75))
+    }
+}
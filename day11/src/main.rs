@@ -1,11 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::stones::Stones;
-
-mod stones;
+use aocrs::solution::Solution;
+use day11::Day11;
 
 fn main() {
-    let stones = Stones::from_str(&get_text("./day11/input.txt").unwrap()).unwrap();
-    println!("part 1: {}", stones.count(25));       // 55312
-    println!("part 2: {}", stones.count(75));       // 65601038650482
+    let input = Day11::parse(&get_text("./day11/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day11::part1(&input).unwrap());
+    println!("part 2: {}", Day11::part2(&input).unwrap());
 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::str::FromStr;
+use aocrs::error::Error;
 use aocrs::math::digits;
+use aocrs::memo::Memo;
 
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
 struct CacheEntry {
@@ -18,49 +20,40 @@ impl CacheEntry {
 pub struct Stones(Vec<i64>);
 
 impl FromStr for Stones {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Stones(s.split(" ").filter_map(|x| i64::from_str(x.trim()).ok()).collect()))
+        let stones = s
+            .split_whitespace()
+            .map(|x| i64::from_str(x).map_err(|e| Error::from(e).with_text(x)))
+            .collect::<Result<_, _>>()?;
+        Ok(Stones(stones))
     }
 }
 
 impl Stones {
     pub fn count(&self, blinks: i32) -> usize {
-        let mut cache: HashMap<CacheEntry, usize> = HashMap::with_capacity(150000);
-        self.0.iter().map(|&x| Self::blink(x, blinks, &mut cache)).sum()
+        let mut memo = Memo::with_cache(HashMap::with_capacity(150000));
+        self.0.iter().map(|&x| Self::blink(x, blinks, &mut memo)).sum()
     }
 
-    fn blink(num: i64, blinks: i32, cache: &mut HashMap<CacheEntry, usize>) -> usize {
+    fn blink(num: i64, blinks: i32, memo: &mut Memo<CacheEntry, usize>) -> usize {
         if blinks == 0 {
             return 1;
         }
 
-        let entry = CacheEntry::new(num, blinks);
-        if cache.contains_key(&entry) {
-            return cache[&entry];
-        }
-
-        if num == 0 {
-            let result = Self::blink(1, blinks - 1, cache);
-            cache.insert(entry, result);
-            return result;
-        }
-
-        let digits = digits(num);
-        if digits % 2 == 0 {
-            let (first, second) = Self::split_num(num, digits);
-            let first_result = Self::blink(first, blinks - 1, cache);
-            cache.insert(CacheEntry::new(first, blinks - 1), first_result);
-
-            let second_result = Self::blink(second, blinks - 1, cache);
-            cache.insert(CacheEntry::new(second, blinks - 1), second_result);
-            return first_result + second_result;
-        }
+        memo.get_or_insert_with(CacheEntry::new(num, blinks), |memo| {
+            if num == 0 {
+                return Self::blink(1, blinks - 1, memo);
+            }
 
-        let result = Self::blink(num * 2024, blinks - 1, cache);
-        cache.insert(entry, result);
-        result
+            let digits = digits(num);
+            if digits.is_multiple_of(2) {
+                let (first, second) = Self::split_num(num, digits);
+                return Self::blink(first, blinks - 1, memo) + Self::blink(second, blinks - 1, memo);
+            }
+            Self::blink(num * 2024, blinks - 1, memo)
+        })
     }
 
     #[inline]
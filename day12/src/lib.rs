@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::garden::Garden;
+
+pub mod garden;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("140"),
+        part2: Expected::Answer("80"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Garden::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.get_cost(true))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.get_cost(false))
+    }
+}
@@ -1,11 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::garden::Garden;
-
-mod garden;
+use aocrs::solution::Solution;
+use day12::Day12;
 
 fn main() {
-    let garden = Garden::from_str(&get_text("./day12/input.txt").unwrap()).unwrap();
-    println!("part 1: {}", garden.get_cost(true));      // 140
-    println!("part 2: {}", garden.get_cost(false));     // 80
+    let input = Day12::parse(&get_text("./day12/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day12::part1(&input).unwrap());
+    println!("part 2: {}", Day12::part2(&input).unwrap());
 }
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use aocrs::error::Error;
+use aocrs::input::{sections, Section};
+use aocrs::solution::{Example, Expected, Solution};
+use crate::machine::Machine;
+
+pub mod machine;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("480"),
+        part2: Expected::Answer("875318608908"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        sections(input).iter().map(Section::parse).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.iter().filter_map(|x| x.win_tokens(0)).sum::<i64>())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.iter().filter_map(|x| x.win_tokens(10000000000000)).sum::<i64>())
+    }
+}
@@ -1,11 +1,7 @@
 use std::str::FromStr;
-use std::sync::LazyLock;
+use aocrs::error::{Error, Reason};
 use aocrs::geo::pos::Pos;
-use regex::Regex;
-
-static RGX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"\d+").unwrap()
-});
+use aocrs::input::ints;
 
 #[derive(Clone, Debug)]
 pub struct Machine {
@@ -15,27 +11,14 @@ pub struct Machine {
 }
 
 impl FromStr for Machine {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<_> = s.lines().collect();
-        let button_a: Vec<_> = RGX
-            .find_iter(lines[0])
-            .filter_map(|m| i64::from_str(m.as_str()).ok())
-            .collect();
-        let button_b: Vec<_> = RGX
-            .find_iter(lines[1])
-            .filter_map(|m| i64::from_str(m.as_str()).ok())
-            .collect();
-        let prize: Vec<_> = RGX
-            .find_iter(lines[2])
-            .filter_map(|m| i64::from_str(m.as_str()).ok())
-            .collect();
-
+        let mut lines = s.lines();
         Ok(Self {
-            button_a: Pos::new(button_a[0], button_a[1]),
-            button_b: Pos::new(button_b[0], button_b[1]),
-            prize: Pos::new(prize[0], prize[1]),
+            button_a: Self::parse_pos(lines.next(), "button A").map_err(|e| e.at_line(1))?,
+            button_b: Self::parse_pos(lines.next(), "button B").map_err(|e| e.at_line(2))?,
+            prize: Self::parse_pos(lines.next(), "prize").map_err(|e| e.at_line(3))?,
         })
     }
 }
@@ -59,6 +42,14 @@ impl Machine {
         }
     }
 
+    fn parse_pos(line: Option<&str>, name: &'static str) -> Result<Pos<i64>, Error> {
+        let line = line.ok_or_else(|| Error::parse("", Reason::Missing(name)))?;
+        match ints(line)?[..] {
+            [x, y] => Ok(Pos::new(x, y)),
+            _ => Err(Error::parse(line, Reason::Invalid("X and Y values"))),
+        }
+    }
+
     #[inline]
     fn determinant(top: &Pos<i64>, bottom: &Pos<i64>) -> i64 {
         top.x * bottom.y - top.y * bottom.x
@@ -1,19 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::machine::Machine;
-
-mod machine;
+use aocrs::solution::Solution;
+use day13::Day13;
 
 fn main() {
-    let input = get_text("./day13/input.txt").unwrap().replace("\r\n", "\n");
-    let machines: Vec<_> = input
-        .split("\n\n")
-        .filter_map(|x| Machine::from_str(x).ok())
-        .collect();
-
-    let win_tokens: i64 = machines.iter().filter_map(|x| x.win_tokens(0)).sum();
-    println!("part 1: {}", win_tokens);     // 480
-
-    let win_tokens: i64 = machines.iter().filter_map(|x| x.win_tokens(10000000000000)).sum();
-    println!("part 2: {}", win_tokens);     // 875318608908
+    let input = Day13::parse(&get_text("./day13/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day13::part1(&input).unwrap());
+    println!("part 2: {}", Day13::part2(&input).unwrap());
 }
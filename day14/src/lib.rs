@@ -0,0 +1,45 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::geo::area::Area;
+use aocrs::error::Error;
+use aocrs::input::parse_lines;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::robot::{find_tree, Quadrant, Robot};
+
+pub mod robot;
+
+const AREA: Area<i64> = Area { max_x: 101, max_y: 103, min_x: 0, min_y: 0 };
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("21"),
+        part2: Expected::Unknown,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, Robot::from_str)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        let (mut top_left, mut top_right, mut bottom_left, mut bottom_right) = (0, 0, 0, 0);
+        for robot in input {
+            match robot.quadrant(&AREA, 100) {
+                Quadrant::TopLeft => top_left += 1,
+                Quadrant::TopRight => top_right += 1,
+                Quadrant::BottomLeft => bottom_left += 1,
+                Quadrant::BottomRight => bottom_right += 1,
+                _ => ()
+            }
+        }
+        Ok(top_left * top_right * bottom_left * bottom_right)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(find_tree(input, &AREA))
+    }
+}
@@ -1,8 +1,11 @@
 use std::collections::HashSet;
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
 use aocrs::geo::area::Area;
 use aocrs::geo::direction::Direction;
 use aocrs::geo::pos::Pos;
+use aocrs::image::Image;
+use aocrs::input::ints;
 
 const MAX_ITER: usize = 20000;
 
@@ -38,16 +41,13 @@ pub struct Robot {
 }
 
 impl FromStr for Robot {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splits: Vec<_> = s.split_whitespace().filter_map(|x| x.get(2..)).collect();
-        let position: Vec<_> = splits[0].split(",").filter_map(|x| i64::from_str(x).ok()).collect();
-        let velocity: Vec<_> = splits[1].split(",").filter_map(|x| i64::from_str(x).ok()).collect();
-        Ok(Self {
-            position: Pos::new(position[0],position[1]),
-            velocity: Pos::new(velocity[0],velocity[1]),
-        })
+        match ints(s)?[..] {
+            [px, py, vx, vy] => Ok(Self { position: Pos::new(px, py), velocity: Pos::new(vx, vy) }),
+            _ => Err(Error::parse(s, Reason::Invalid("robot, expected `p=x,y v=x,y`"))),
+        }
     }
 }
 
@@ -87,6 +87,13 @@ pub fn find_tree(robots: &[Robot], area: &Area<i64>) -> usize {
     result
 }
 
+/// Returns an image of the robots' current positions, for inspecting candidate frames of [`find_tree`]. Cells with at
+/// least one robot are green. Like the robots' movement, the maximum of `area` is exclusive.
+pub fn frame(robots: &[Robot], area: &Area<i64>) -> Image {
+    let area = Area { max_x: area.max_x - 1, max_y: area.max_y - 1, ..*area };
+    Image::from_positions(robots.iter().map(|x| x.position), &area, |count| if count > 0 { [0, 200, 0] } else { [0; 3] })
+}
+
 fn has_tree(robots: &[Robot]) -> bool {
     let set: HashSet<_> = robots.iter().map(|x| x.position).collect();
     for item in &set {
@@ -108,3 +115,24 @@ fn has_tree(robots: &[Robot]) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const AREA_EXAMPLE: Area<i64> = Area { max_x: 11, max_y: 7, min_x: 0, min_y: 0 };
+
+    #[test]
+    fn test_frame() {
+        let area = Area { max_x: 3, max_y: 2, min_x: 0, min_y: 0 };
+        let robots = [Robot::from_str("p=0,0 v=1,1").unwrap(), Robot::from_str("p=2,1 v=-1,0").unwrap()];
+        let sut = frame(&robots, &area);
+        assert_eq!((sut.width, sut.height), (3, 2));
+        assert_eq!(sut.pixels, vec![[0, 200, 0], [0; 3], [0; 3], [0; 3], [0; 3], [0, 200, 0]]);
+
+        let robots: Vec<_> = robots.iter().map(|x| x.wait_once(&area)).collect();
+        let sut = frame(&robots, &area);
+        assert_eq!(sut.pixels, vec![[0; 3], [0; 3], [0; 3], [0; 3], [0, 200, 0], [0; 3]]);
+        assert_eq!(frame(&robots, &AREA_EXAMPLE).width, 11);
+    }
+}
@@ -1,12 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::map::Map;
-
-mod map;
+use aocrs::solution::Solution;
+use day15::Day15;
 
 fn main() {
-    let input = get_text("./day15/input.txt").unwrap();
-    let map = Map::from_str(&input.replace("\r\n", "\n")).unwrap();
-    println!("part 1: {}", map.gps());      // 10092
-    println!("part 2: {}", map.big_gps());  // 9021     TODO
+    let input = Day15::parse(&get_text("./day15/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day15::part1(&input).unwrap());
+    println!("part 2: {}", Day15::part2(&input).unwrap());
 }
@@ -1,7 +1,9 @@
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
 use aocrs::geo::direction::Direction;
-use aocrs::geo::grid::{CharGrid, ParseGridError};
+use aocrs::geo::grid::CharGrid;
 use aocrs::geo::pos::PosIdx;
+use aocrs::input::sections;
 
 const AIR: u8 = b'.';
 const BOX: u8 = b'O';
@@ -18,94 +20,77 @@ pub struct Map {
 }
 
 impl FromStr for Map {
-    type Err = ParseGridError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splits: Vec<_> = s.split("\n\n").collect();
-        let grid = CharGrid::from_str(splits[0])?;
-        let moves: Vec<_> = splits[1].replace("\n", "").into_bytes();
-        let start_pos = grid.find(b'@').unwrap();
+        let [grid, moves] = sections(s)[..] else {
+            return Err(Error::parse("", Reason::Invalid("input, expected map and moves sections")));
+        };
+        let grid = grid.parse::<CharGrid>()?;
+        let moves: Vec<_> = moves.text.lines().collect::<String>().into_bytes();
+        let start_pos = grid.find(ROBOT).ok_or_else(|| Error::parse("", Reason::Missing("robot `@`")))?;
         Ok(Map { grid, moves, start_pos })
     }
 }
 
 impl Map {
     pub fn gps(&self) -> usize {
-        let mut grid = self.grid.clone();
-        let mut pos = self.start_pos;
-        for &m in &self.moves {
-            let mut dir = Direction::from(m);
-            if dir == Direction::Up || dir == Direction::Down {
-                dir = dir.back();
-            }
-            pos = move_dir(&mut grid, pos, dir);
-        }
-
-        let mut result = 0;
-        for pos in grid.area() {
-            if grid[pos] == BOX {
-                result += 100 * pos.y + pos.x;
-            }
-        }
-        result
+        gps(self.grid.clone(), self.start_pos, &self.moves)
     }
 
     pub fn big_gps(&self) -> usize {
-        let mut grid = expand_map(&self.grid);
-        0
+        let start_pos = PosIdx::new(self.start_pos.x * 2, self.start_pos.y);
+        gps(expand_map(&self.grid), start_pos, &self.moves)
     }
 }
 
-fn expand_map(grid: &CharGrid) -> CharGrid {
-    let mut data: Vec<u8> = Vec::with_capacity(grid.width * 2 * grid.height);
-    let area = grid.area();
-    for pos in area {
-        if grid[pos] == WALL {
-            data.extend([WALL; 2].iter());
-        } else if grid[pos] == BOX {
-            data.extend([BOX_LEFT, BOX_RIGHT].iter());
-        } else if grid[pos] == ROBOT {
-            data.extend([ROBOT, AIR].iter());
-        } else {
-            data.extend([AIR; 2].iter());
+/// Moves the robot and returns the sum of the GPS coordinates of the boxes, measured from their left edges.
+fn gps(mut grid: CharGrid, mut pos: PosIdx, moves: &[u8]) -> usize {
+    for &m in moves {
+        pos = move_dir(&mut grid, pos, Direction::from(m));
+    }
+
+    let mut result = 0;
+    for pos in grid.area() {
+        if grid[pos] == BOX || grid[pos] == BOX_LEFT {
+            result += 100 * pos.y + pos.x;
         }
     }
-    CharGrid::with_data(grid.width * 2, &data).unwrap()
+    result
 }
 
-fn move_dir(grid: &mut CharGrid, pos: PosIdx, dir: Direction) -> PosIdx {
-    let air = match next_air(grid, pos, dir) {
-        Some(x) => x,
-        None => return pos,
-    };
-
-    let new_pos = pos.dest(1, dir);
-    grid[air] = BOX;
-    grid[pos] = AIR;
-    grid[new_pos] = ROBOT;
-    new_pos
+fn expand_map(grid: &CharGrid) -> CharGrid {
+    grid.scale(2, 1, |&x| match x {
+        WALL => [WALL, WALL],
+        BOX => [BOX_LEFT, BOX_RIGHT],
+        ROBOT => [ROBOT, AIR],
+        _ => [AIR, AIR],
+    }).unwrap()
 }
 
-fn next_air(grid: &CharGrid, pos: PosIdx, dir: Direction) -> Option<PosIdx> {
-    let mut dist = 1;
-    while let Some(dest) = pos.checked_dest(dist, dir) {
-        if grid[dest] == WALL {
-            return None;
+/// Moves the robot one step, pushing every box in the way unless one of them is blocked by a wall.
+fn move_dir(grid: &mut CharGrid, pos: PosIdx, dir: Direction) -> PosIdx {
+    let mut pushed = vec![pos];
+    let mut idx = 0;
+    while idx < pushed.len() {
+        let next = pushed[idx].screen_dest(1, dir);
+        idx += 1;
+        if pushed.contains(&next) {
+            continue;
         }
-        if grid[dest] == AIR {
-            return Some(dest);
+        match grid[next] {
+            WALL => return pos,
+            BOX => pushed.push(next),
+            BOX_LEFT => pushed.extend([next, next.screen_dest(1, Direction::Right)]),
+            BOX_RIGHT => pushed.extend([next, next.screen_dest(1, Direction::Left)]),
+            _ => (),
         }
-        dist += 1;
     }
-    None
-}
 
-fn print_map(grid: &CharGrid) {
-    for pos in grid.area() {
-        if pos.x == 0 {
-            println!();
-        }
-        print!("{}", grid[pos] as char);
+    // Cells are pushed in order of distance from the robot, so the farthest are moved first.
+    for &cell in pushed.iter().rev() {
+        grid[cell.screen_dest(1, dir)] = grid[cell];
+        grid[cell] = AIR;
     }
-    println!();
+    pos.screen_dest(1, dir)
 }
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::map::Map;
+
+pub mod map;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("7036"),
+        part2: Expected::Answer("45"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Map::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        input.score().ok_or(Error::NoAnswer("no path from the start to the end tile"))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        input.seats().ok_or(Error::NoAnswer("no path from the start to the end tile"))
+    }
+}
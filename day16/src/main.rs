@@ -1,12 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::map::Map;
-
-mod map;
-mod state;
+use aocrs::solution::Solution;
+use day16::Day16;
 
 fn main() {
-    let map = Map::from_str(&get_text("./day16/input.txt").unwrap()).unwrap();
-    println!("part 1: {}", map.score());    // 7036
-    println!("part 2: {}", map.seats());    // 45
+    let input = Day16::parse(&get_text("./day16/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day16::part1(&input).unwrap());
+    println!("part 2: {}", Day16::part2(&input).unwrap());
 }
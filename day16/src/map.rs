@@ -1,10 +1,10 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashSet};
+use std::collections::HashSet;
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
 use aocrs::geo::direction::{Direction, DirectionalPos};
-use aocrs::geo::grid::{CharGrid, ParseGridError};
+use aocrs::geo::grid::CharGrid;
 use aocrs::geo::pos::PosIdx;
-use crate::state::State;
+use aocrs::search::{dijkstra_all, ShortestPaths};
 
 const END: u8 = b'E';
 const START: u8 = b'S';
@@ -17,52 +17,47 @@ pub struct Map {
 }
 
 impl FromStr for Map {
-    type Err = ParseGridError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let grid = CharGrid::from_str(s)?;
-        let start = grid.find(START).unwrap();
+        let start = grid.find(START).ok_or_else(|| Error::parse("", Reason::Missing("start tile `S`")))?;
+        grid.find(END).ok_or_else(|| Error::parse("", Reason::Missing("end tile `E`")))?;
         Ok(Self { grid, start })
     }
 }
 
 impl Map {
-    pub fn score(&self) -> usize {
-        self.dijkstra()[0].cost
+    /// Returns the lowest score of a path from the start to the end tile, or [`None`] if the end is unreachable.
+    pub fn score(&self) -> Option<usize> {
+        self.dijkstra().goal_cost()
     }
 
-    pub fn seats(&self) -> usize {
-        let mut paths = HashSet::new();
-        for state in self.dijkstra() {
-            paths.extend(state.path);
-        }
-        paths.len()
+    /// Returns the number of tiles on any best path, or [`None`] if the end is unreachable.
+    pub fn seats(&self) -> Option<usize> {
+        let paths = self.dijkstra();
+        paths.goal_cost()?;
+        let seats: HashSet<PosIdx> = paths.nodes().map(|x| x.pos).collect();
+        Some(seats.len())
     }
 
-    fn dijkstra(&self) -> Vec<State> {
-        let mut processed = HashSet::with_capacity(self.grid.size());
-        let mut pq = BinaryHeap::new();
-        processed.insert(DirectionalPos::new(self.start, Direction::Right));
-        pq.push(Reverse(State::new(0, DirectionalPos::new(self.start, Direction::Right), HashSet::new())));
-
-        let mut best_cost = usize::MAX;
-        let mut states = Vec::new();
-        while let Some(Reverse(state)) = pq.pop() {
-            processed.insert(state.dpos);
-            if self.grid[state.dpos.pos] == END {
-                if state.cost <= best_cost {
-                    best_cost = state.cost;
-                    states.push(state.clone());
-                } else if state.cost > best_cost {
-                    break;
-                }
-            }
+    fn dijkstra(&self) -> ShortestPaths<DirectionalPos<usize>, usize> {
+        dijkstra_all(
+            DirectionalPos::new(self.start, Direction::Right),
+            |&dpos| self.neighbours(dpos),
+            |dpos| self.grid[dpos.pos] == END,
+        )
+    }
 
-            state.neighbours()
-                .into_iter()
-                .filter(|n| !processed.contains(&n.dpos) && self.grid[n.dpos.pos] != WALL)
-                .for_each(|n| pq.push(Reverse(n)));
+    fn neighbours(&self, dpos: DirectionalPos<usize>) -> Vec<(DirectionalPos<usize>, usize)> {
+        let mut result = vec![
+            (dpos.update_direction(dpos.direction.left()), 1000),
+            (dpos.update_direction(dpos.direction.right()), 1000),
+        ];
+        let next = dpos.next(1);
+        if self.grid[next.pos] != WALL {
+            result.push((next, 1));
         }
-        states
+        result
     }
 }
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use aocrs::error::{Error, Reason};
+use crate::debugger::Debugger;
+use crate::instruction::Opcode;
+
+/// Assembles mnemonic source into a program, such as the output of
+/// [`Debugger::disassemble`](crate::debugger::Debugger::disassemble).
+///
+/// Each line holds an instruction like `adv 3`, `out B` or `jnz loop`, optionally preceded by a `label:`. Combo
+/// operands are `0` to `3`, `A`, `B` or `C`, and literal operands are `0` to `7`. The operand of `bxc` is ignored and
+/// defaults to `0`. Text after `;` is a comment.
+pub fn assemble(source: &str) -> Result<Vec<i8>, Error> {
+    let mut labels: HashMap<&str, usize> = HashMap::new();
+    let mut lines = Vec::new();
+    for (idx, line) in source.lines().enumerate() {
+        let mut text = line.split(';').next().unwrap_or_default().trim();
+        if let Some((label, rest)) = text.split_once(':') {
+            let label = label.trim();
+            if label.is_empty() || label.contains(char::is_whitespace) {
+                return Err(Error::parse(label, Reason::Invalid("label")).at_line(idx + 1));
+            }
+            if labels.insert(label, lines.len() * 2).is_some() {
+                return Err(Error::parse(label, Reason::Invalid("label, already defined")).at_line(idx + 1));
+            }
+            text = rest.trim();
+        }
+
+        if !text.is_empty() {
+            lines.push((idx + 1, text));
+        }
+    }
+
+    let mut program = Vec::with_capacity(lines.len() * 2);
+    for (line, text) in lines {
+        let (opcode, operand) = instruction(text, &labels).map_err(|e| e.with_text(text).at_line(line))?;
+        program.extend([opcode.bits(), operand]);
+    }
+    Ok(program)
+}
+
+/// Assembles mnemonic source into puzzle input text, with the registers and the program as comma separated values.
+pub fn assemble_input(source: &str, reg_a: u64, reg_b: u64, reg_c: u64) -> Result<String, Error> {
+    Ok(Debugger::new(reg_a, reg_b, reg_c, assemble(source)?).to_string())
+}
+
+/// Assembles a single instruction, resolving jump labels to addresses.
+fn instruction(text: &str, labels: &HashMap<&str, usize>) -> Result<(Opcode, i8), Error> {
+    let mut parts = text.split_whitespace();
+    let opcode = Opcode::from_str(parts.next().unwrap_or_default())?;
+    let operand = parts.next();
+    if let Some(extra) = parts.next() {
+        return Err(Error::parse(extra, Reason::Invalid("instruction, expected a single operand")));
+    }
+
+    let operand = match (opcode, operand) {
+        (Opcode::Bxc, None) => 0,
+        (_, None) => return Err(Error::parse(text, Reason::Missing("operand"))),
+        (Opcode::Jnz, Some(target)) if labels.contains_key(target) => match labels[target] {
+            address @ 0..=7 => address as i8,
+            _ => return Err(Error::parse(target, Reason::Invalid("jump target, expected an address of 0-7"))),
+        },
+        (Opcode::Jnz, Some(target)) if !target.starts_with(|c: char| c.is_ascii_digit()) => {
+            return Err(Error::parse(target, Reason::Invalid("jump target, label not defined")));
+        },
+        (opcode, Some(operand)) if opcode.is_combo() => combo(operand)?,
+        (_, Some(operand)) => literal(operand)?,
+    };
+    Ok((opcode, operand))
+}
+
+fn combo(s: &str) -> Result<i8, Error> {
+    match s {
+        "A" => Ok(4),
+        "B" => Ok(5),
+        "C" => Ok(6),
+        _ => match i8::from_str(s) {
+            Ok(value @ 0..=3) => Ok(value),
+            _ => Err(Error::parse(s, Reason::Invalid("combo operand, expected 0-3, A, B or C"))),
+        },
+    }
+}
+
+fn literal(s: &str) -> Result<i8, Error> {
+    match i8::from_str(s) {
+        Ok(value @ 0..=7) => Ok(value),
+        _ => Err(Error::parse(s, Reason::Invalid("literal operand, expected 0-7"))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_assemble() {
+        assert_eq!(assemble("adv 3\nout A\njnz 0"), Ok(vec![0, 3, 5, 4, 3, 0]));
+        assert_eq!(assemble("  bst A ; B = A % 8\n\nbxc\ncdv B"), Ok(vec![2, 4, 4, 0, 7, 5]));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let program = vec![2, 4, 1, 1, 7, 5, 1, 5, 4, 0, 5, 5, 0, 3, 3, 0];
+        let source = Debugger::new(0, 0, 0, program.clone()).disassemble();
+        assert_eq!(assemble(&source), Ok(program));
+    }
+
+    #[test]
+    fn test_labels() {
+        let sut = assemble("start: adv 3\nloop:\n  out A\n  jnz loop\njnz start");
+        assert_eq!(sut, Ok(vec![0, 3, 5, 4, 3, 2, 3, 0]));
+
+        let sut = assemble("adv 1\nadv 1\nadv 1\nadv 1\nfar: adv 1\njnz far").unwrap_err();
+        assert_eq!(sut.to_string(), "line 6: invalid jump target, expected an address of 0-7 in `far`");
+        assert!(assemble("a: adv 1\na: adv 1").is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        let sut = assemble("adv 3\nout 7").unwrap_err();
+        assert_eq!(sut.to_string(), "line 2: invalid combo operand, expected 0-3, A, B or C in `7`");
+
+        let sut = assemble("bxl 8").unwrap_err();
+        assert_eq!(sut.to_string(), "line 1: invalid literal operand, expected 0-7 in `8`");
+
+        let sut = assemble("jnz end").unwrap_err();
+        assert_eq!(sut.to_string(), "line 1: invalid jump target, label not defined in `end`");
+
+        let sut = assemble("adv 3 4").unwrap_err();
+        assert_eq!(sut.to_string(), "line 1: invalid instruction, expected a single operand in `4`");
+
+        assert!(assemble("mul 3").is_err());
+        assert!(assemble("adv").is_err());
+    }
+
+    #[test]
+    fn test_assemble_input() {
+        let sut = assemble_input("adv 3\nout A\njnz 0", 2024, 0, 0).unwrap();
+        assert_eq!(sut, "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0");
+        assert_eq!(sut, include_str!("../example.txt").trim_end());
+    }
+}
@@ -1,5 +1,29 @@
-use std::num::ParseIntError;
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
+use aocrs::input::{list, records};
+use crate::instruction::{Instruction, Opcode};
+
+/// Why the [`Debugger`] stopped running.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stop {
+    /// The instruction pointer moved past the end of the program.
+    Halted,
+    /// The instruction pointer reached a breakpoint.
+    Breakpoint(usize),
+}
+
+/// An executed instruction and the register values after it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Trace {
+    pub instruction_ptr: usize,
+    pub instruction: Instruction,
+    pub reg_a: u64,
+    pub reg_b: u64,
+    pub reg_c: u64,
+    pub output: Option<u64>,
+}
 
 #[derive(Clone, Debug)]
 pub struct Debugger {
@@ -8,71 +32,187 @@ pub struct Debugger {
     reg_c: u64,
     program: Vec<i8>,
     instruction_ptr: usize,
+    breakpoints: BTreeSet<usize>,
+}
+
+impl Display for Debugger {
+    /// Renders the registers and program in the puzzle input format.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let program: Vec<_> = self.program.iter().map(|x| x.to_string()).collect();
+        writeln!(f, "Register A: {}", self.reg_a)?;
+        writeln!(f, "Register B: {}", self.reg_b)?;
+        writeln!(f, "Register C: {}", self.reg_c)?;
+        writeln!(f)?;
+        write!(f, "Program: {}", program.join(","))
+    }
 }
 
 impl FromStr for Debugger {
-    type Err = ParseIntError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<_> = s.trim().lines().collect();
-        let reg_a = u64::from_str(lines[0].split(": ").collect::<Vec<_>>()[1])?;
-        let reg_b = u64::from_str(lines[1].split(": ").collect::<Vec<_>>()[1])?;
-        let reg_c = u64::from_str(lines[2].split(": ").collect::<Vec<_>>()[1])?;
-        let program_str = lines[4].split(": ").collect::<Vec<_>>()[1];
-        let program: Vec<_> = program_str.split(",").filter_map(|s| i8::from_str(s).ok()).collect();
-        Ok(Self { reg_a, reg_b, reg_c, program, instruction_ptr: 0 })
+        let records = records(s)?;
+        let reg_a = records.parse("Register A")?;
+        let reg_b = records.parse("Register B")?;
+        let reg_c = records.parse("Register C")?;
+        let program = records.parse_with("Program", |s| {
+            let program: Vec<i8> = list(s)?;
+            match program.iter().find(|x| !(0..8).contains(*x)) {
+                Some(x) => Err(Error::parse(&x.to_string(), Reason::Invalid("3-bit number"))),
+                None => Ok(program),
+            }
+        })?;
+        Ok(Self::new(reg_a, reg_b, reg_c, program))
     }
 }
 
 impl Debugger {
+    pub fn new(reg_a: u64, reg_b: u64, reg_c: u64, program: Vec<i8>) -> Self {
+        Self { reg_a, reg_b, reg_c, program, instruction_ptr: 0, breakpoints: BTreeSet::new() }
+    }
+
+    /// Runs the program until it halts, ignoring breakpoints.
     pub fn run(&mut self) -> Vec<u64> {
         let mut results: Vec<u64> = vec![];
-        while self.instruction_ptr < self.program.len() - 1 {
-            let instruction = self.program[self.instruction_ptr];
-            let operand = self.program[self.instruction_ptr + 1];
-            let (jump, result) = self.calculate(instruction, operand);
+        while let Some(trace) = self.step() {
+            results.extend(trace.output);
+        }
+        results
+    }
+
+    /// Returns every positive initial register A, in ascending order, for which the program outputs itself. Assumes
+    /// the program outputs once per loop and shifts A right by 3 bits per loop, so A is built 3 bits at a time from the
+    /// last output backwards. Returns no values if the program does not have a single `out` and an `adv 3`.
+    pub fn reverse(&self) -> Vec<u64> {
+        let instructions = self.instructions();
+        let outputs = instructions.iter().filter(|x| x.opcode == Opcode::Out).count();
+        if outputs != 1 || !instructions.contains(&Instruction::new(Opcode::Adv, 3)) {
+            return Vec::new();
+        }
+
+        let mut candidates: BTreeSet<u64> = BTreeSet::from([0]);
+        for idx in (0..self.program.len()).rev() {
+            let expected: Vec<_> = self.program[idx..].iter().map(|&x| x as u64).collect();
+            candidates = candidates
+                .iter()
+                .filter(|&&a| a.leading_zeros() >= 3)
+                .flat_map(|&a| (0..8).map(move |bits| a << 3 | bits))
+                .filter(|&a| self.outputs_with(a, expected.len()).as_ref() == Some(&expected))
+                .collect();
+        }
+        candidates.into_iter().filter(|&a| a > 0).collect()
+    }
+
+    /// Executes the instruction at the instruction pointer. Returns [`None`] if the program has halted.
+    pub fn step(&mut self) -> Option<Trace> {
+        let instruction_ptr = self.instruction_ptr;
+        let instruction = Instruction::decode(&self.program, instruction_ptr)?;
+        let output = self.execute(instruction);
+        Some(Trace { instruction_ptr, instruction, reg_a: self.reg_a, reg_b: self.reg_b, reg_c: self.reg_c, output })
+    }
 
-            if let Some(num) = result {
-                results.push(num)
+    /// Runs the program until it halts or the instruction pointer reaches a breakpoint, always executing at least one
+    /// instruction. Returns why it stopped and the values output on the way.
+    pub fn resume(&mut self) -> (Stop, Vec<u64>) {
+        let mut results: Vec<u64> = vec![];
+        while let Some(trace) = self.step() {
+            results.extend(trace.output);
+            if self.breakpoints.contains(&self.instruction_ptr) {
+                return (Stop::Breakpoint(self.instruction_ptr), results);
             }
-            if !jump {
-                self.instruction_ptr += 2;
+        }
+        (Stop::Halted, results)
+    }
+
+    /// Runs the program until it halts, ignoring breakpoints, and returns every executed instruction.
+    pub fn trace(&mut self) -> Vec<Trace> {
+        std::iter::from_fn(|| self.step()).collect()
+    }
+
+    /// Adds a breakpoint at an instruction pointer. Returns false if it was already set.
+    #[inline]
+    pub fn add_breakpoint(&mut self, instruction_ptr: usize) -> bool {
+        self.breakpoints.insert(instruction_ptr)
+    }
+
+    /// Removes a breakpoint at an instruction pointer. Returns false if it was not set.
+    #[inline]
+    pub fn remove_breakpoint(&mut self, instruction_ptr: usize) -> bool {
+        self.breakpoints.remove(&instruction_ptr)
+    }
+
+    #[inline]
+    pub fn is_halted(&self) -> bool {
+        Instruction::decode(&self.program, self.instruction_ptr).is_none()
+    }
+
+    #[inline]
+    pub fn instruction_ptr(&self) -> usize {
+        self.instruction_ptr
+    }
+
+    /// Returns the values of registers A, B and C.
+    #[inline]
+    pub fn registers(&self) -> (u64, u64, u64) {
+        (self.reg_a, self.reg_b, self.reg_c)
+    }
+
+    #[inline]
+    pub fn program(&self) -> &[i8] {
+        &self.program
+    }
+
+    /// Returns the instructions of the program, ignoring a trailing opcode without an operand.
+    #[inline]
+    pub fn instructions(&self) -> Vec<Instruction> {
+        (0..self.program.len()).step_by(2).filter_map(|ptr| Instruction::decode(&self.program, ptr)).collect()
+    }
+
+    /// Renders the program with one mnemonic per line, such as `adv 3` or `out A`.
+    pub fn disassemble(&self) -> String {
+        self.instructions().iter().map(|x| format!("{}\n", x)).collect()
+    }
+
+    /// Runs a copy of the program with a different register A, stopping after more than `limit` outputs. Returns
+    /// [`None`] if it takes more steps than running the whole program once per output, which a program looping once
+    /// per output never needs.
+    fn outputs_with(&self, reg_a: u64, limit: usize) -> Option<Vec<u64>> {
+        let mut debugger = Self::new(reg_a, self.reg_b, self.reg_c, self.program.clone());
+        let mut results: Vec<u64> = vec![];
+        for _ in 0..(limit + 1) * self.program.len() {
+            let Some(trace) = debugger.step() else {
+                return Some(results);
+            };
+            results.extend(trace.output);
+            if results.len() > limit {
+                return Some(results);
             }
         }
-        results
+        None
     }
 
-    pub fn reverse(&self) -> u64 {
-        0
-    }
-
-    fn calculate(&mut self, instruction: i8, operand: i8) -> (bool, Option<u64>) {
-        if instruction == 0 {                                       // adv
-            self.reg_a /= 2_u64.pow(self.combo_operand(operand) as u32);
-            (false, None)
-        } else if instruction == 1 {                                // bxl
-            self.reg_b ^= operand as u64;
-            (false, None)
-        } else if instruction == 2 {                                // bst
-            self.reg_b = self.combo_operand(operand) % 8;
-            (false, None)
-        } else if instruction == 3 && self.reg_a != 0 {             // jnz
-            self.instruction_ptr = operand as usize;
-            (true, None)
-        } else if instruction == 4 {                                // bxc
-            self.reg_b ^= self.reg_c;
-            (false, None)
-        } else if instruction == 5 {                                // out
-            (false, Some(self.combo_operand(operand) % 8))
-        } else if instruction == 6 {                                // bdv
-            self.reg_b = self.reg_a / 2_u64.pow(self.combo_operand(operand) as u32);
-            (false, None)
-        } else if instruction == 7 {                                // cdv
-            self.reg_c = self.reg_a / 2_u64.pow(self.combo_operand(operand) as u32);
-            (false, None)
-        } else {
-            (false, None)
+    /// Executes an instruction, moving the instruction pointer. Returns the output value, if any.
+    fn execute(&mut self, instruction: Instruction) -> Option<u64> {
+        let operand = instruction.operand;
+        self.instruction_ptr += 2;
+        match instruction.opcode {
+            Opcode::Adv => self.reg_a = self.divide(operand),
+            Opcode::Bxl => self.reg_b ^= operand as u64,
+            Opcode::Bst => self.reg_b = self.combo_operand(operand) % 8,
+            Opcode::Jnz if self.reg_a != 0 => self.instruction_ptr = operand as usize,
+            Opcode::Jnz => (),
+            Opcode::Bxc => self.reg_b ^= self.reg_c,
+            Opcode::Out => return Some(self.combo_operand(operand) % 8),
+            Opcode::Bdv => self.reg_b = self.divide(operand),
+            Opcode::Cdv => self.reg_c = self.divide(operand),
         }
+        None
+    }
+
+    /// Returns register A divided by 2 to the power of a combo operand.
+    #[inline]
+    fn divide(&self, operand: i8) -> u64 {
+        u32::try_from(self.combo_operand(operand)).ok().and_then(|x| self.reg_a.checked_shr(x)).unwrap_or(0)
     }
 
     #[inline]
@@ -85,3 +225,98 @@ impl Debugger {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0";
+
+    #[test]
+    fn test_parse() {
+        let sut = Debugger::from_str(EXAMPLE).unwrap();
+        assert_eq!(sut.registers(), (729, 0, 0));
+        assert_eq!(sut.program(), &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(sut.to_string(), EXAMPLE);
+        assert!(Debugger::from_str("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let mut sut = Debugger::from_str(EXAMPLE).unwrap();
+        assert_eq!(sut.run(), vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+        assert!(sut.is_halted());
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let sut = Debugger::from_str(EXAMPLE).unwrap();
+        assert_eq!(sut.disassemble(), "adv 1\nout A\njnz 0\n");
+        assert_eq!(Debugger::new(0, 0, 0, vec![2, 6, 4, 0, 1]).disassemble(), "bst C\nbxc 0\n");
+    }
+
+    #[test]
+    fn test_step() {
+        let mut sut = Debugger::from_str(EXAMPLE).unwrap();
+        let trace = sut.step().unwrap();
+        assert_eq!(trace.instruction, Instruction::new(Opcode::Adv, 1));
+        assert_eq!((trace.instruction_ptr, trace.reg_a, trace.output), (0, 364, None));
+        assert_eq!(sut.step().unwrap().output, Some(4));
+        sut.step();
+        assert_eq!(sut.instruction_ptr(), 0);
+
+        let mut sut = Debugger::new(0, 0, 0, vec![5, 4]);
+        assert_eq!(sut.step().unwrap().output, Some(0));
+        assert_eq!(sut.step(), None);
+    }
+
+    #[test]
+    fn test_resume() {
+        let mut sut = Debugger::from_str(EXAMPLE).unwrap();
+        assert!(sut.add_breakpoint(2));
+        assert!(!sut.add_breakpoint(2));
+        assert_eq!(sut.resume(), (Stop::Breakpoint(2), vec![]));
+        assert_eq!(sut.resume(), (Stop::Breakpoint(2), vec![4]));
+        assert_eq!(sut.registers(), (182, 0, 0));
+
+        assert!(sut.remove_breakpoint(2));
+        assert!(!sut.remove_breakpoint(2));
+        assert_eq!(sut.resume(), (Stop::Halted, vec![6, 3, 5, 6, 3, 5, 2, 1, 0]));
+        assert_eq!(sut.resume(), (Stop::Halted, vec![]));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let sut = Debugger::new(2024, 0, 0, vec![0, 3, 5, 4, 3, 0]);
+        assert_eq!(sut.reverse(), (117440..=117447).collect::<Vec<_>>());
+
+        let mut sut = Debugger::new(117440, 0, 0, vec![0, 3, 5, 4, 3, 0]);
+        assert_eq!(sut.run(), vec![0, 3, 5, 4, 3, 0]);
+
+        assert_eq!(Debugger::new(0, 0, 0, vec![0, 3, 5, 5, 3, 0]).reverse(), vec![]);
+        assert_eq!(Debugger::new(0, 0, 0, vec![0, 1, 5, 4, 3, 0]).reverse(), vec![]);
+    }
+
+    #[test]
+    fn test_reverse_without_progress() {
+        // Loops forever without output for any non-zero A.
+        let sut = Debugger::new(0, 0, 0, vec![3, 0, 0, 3, 5, 4]);
+        assert_eq!(sut.reverse(), vec![]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let sut: Vec<_> = Debugger::new(0, 0, 9, vec![2, 6]).trace().iter().map(|x| x.reg_b).collect();
+        assert_eq!(sut, vec![1]);
+
+        let sut: Vec<_> = Debugger::new(0, 29, 0, vec![1, 7]).trace().iter().map(|x| x.reg_b).collect();
+        assert_eq!(sut, vec![26]);
+
+        let sut: Vec<_> = Debugger::new(0, 2024, 43690, vec![4, 0]).trace().iter().map(|x| x.reg_b).collect();
+        assert_eq!(sut, vec![44354]);
+
+        let sut = Debugger::new(10, 0, 0, vec![5, 0, 5, 1, 5, 4]).trace();
+        assert_eq!(sut.iter().map(|x| x.output).collect::<Vec<_>>(), vec![Some(0), Some(1), Some(2)]);
+        assert_eq!(sut.iter().map(|x| x.instruction_ptr).collect::<Vec<_>>(), vec![0, 2, 4]);
+    }
+}
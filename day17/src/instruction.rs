@@ -0,0 +1,140 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use aocrs::error::{Error, Reason};
+
+/// The opcodes of the 3-bit computer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Opcode {
+    Adv = 0,
+    Bxl = 1,
+    Bst = 2,
+    Jnz = 3,
+    Bxc = 4,
+    Out = 5,
+    Bdv = 6,
+    Cdv = 7,
+}
+
+impl Display for Opcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+impl FromStr for Opcode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .into_iter()
+            .find(|x| x.mnemonic() == s)
+            .ok_or_else(|| Error::parse(s, Reason::Invalid("mnemonic")))
+    }
+}
+
+impl Opcode {
+    /// Returns every [`Opcode`], ordered by value.
+    #[inline]
+    pub fn all() -> [Opcode; 8] {
+        [Opcode::Adv, Opcode::Bxl, Opcode::Bst, Opcode::Jnz, Opcode::Bxc, Opcode::Out, Opcode::Bdv, Opcode::Cdv]
+    }
+
+    /// Returns the [`Opcode`] of a 3-bit value.
+    #[inline]
+    pub fn from_bits(value: i8) -> Self {
+        Self::all()[(value & 7) as usize]
+    }
+
+    /// Returns the 3-bit value of this [`Opcode`].
+    #[inline]
+    pub fn bits(&self) -> i8 {
+        *self as i8
+    }
+
+    #[inline]
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    /// Returns whether the operand of this [`Opcode`] is a combo operand rather than a literal.
+    #[inline]
+    pub fn is_combo(&self) -> bool {
+        matches!(self, Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv)
+    }
+}
+
+/// A single instruction: an [`Opcode`] and its operand.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: i8,
+}
+
+impl Display for Instruction {
+    /// Renders the instruction as a mnemonic with its operand, resolving combo operands to `A`, `B` or `C`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.opcode.is_combo(), self.operand) {
+            (true, 4) => write!(f, "{} A", self.opcode),
+            (true, 5) => write!(f, "{} B", self.opcode),
+            (true, 6) => write!(f, "{} C", self.opcode),
+            (true, 7) => write!(f, "{} ?", self.opcode),
+            (_, operand) => write!(f, "{} {}", self.opcode, operand),
+        }
+    }
+}
+
+impl Instruction {
+    #[inline]
+    pub fn new(opcode: Opcode, operand: i8) -> Self {
+        Self { opcode, operand }
+    }
+
+    /// Returns the [`Instruction`] at an instruction pointer, or [`None`] if it is past the end of the program.
+    #[inline]
+    pub fn decode(program: &[i8], ptr: usize) -> Option<Self> {
+        match program.get(ptr..ptr + 2)? {
+            &[opcode, operand] => Some(Self::new(Opcode::from_bits(opcode), operand)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(Opcode::from_bits(5), Opcode::Out);
+        assert!(Opcode::all().iter().all(|x| Opcode::from_bits(x.bits()) == *x));
+        assert_eq!(Opcode::from_str("bxc"), Ok(Opcode::Bxc));
+        assert!(Opcode::from_str("mul").is_err());
+        assert!(Opcode::Cdv.is_combo());
+        assert!(!Opcode::Jnz.is_combo());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Instruction::new(Opcode::Adv, 3).to_string(), "adv 3");
+        assert_eq!(Instruction::new(Opcode::Out, 5).to_string(), "out B");
+        assert_eq!(Instruction::new(Opcode::Bxl, 5).to_string(), "bxl 5");
+        assert_eq!(Instruction::new(Opcode::Bst, 7).to_string(), "bst ?");
+    }
+
+    #[test]
+    fn test_decode() {
+        let program = [0, 3, 5, 4, 3];
+        assert_eq!(Instruction::decode(&program, 2), Some(Instruction::new(Opcode::Out, 4)));
+        assert_eq!(Instruction::decode(&program, 4), None);
+        assert_eq!(Instruction::decode(&program, 6), None);
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::debugger::Debugger;
+
+pub mod assembler;
+pub mod debugger;
+pub mod instruction;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Debugger;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("5,7,3,0"),
+        part2: Expected::Answer("117440"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Debugger::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        let results: Vec<_> = input
+            .clone()
+            .run()
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        Ok(results.join(","))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        input.reverse().first().copied().ok_or(Error::NoAnswer("no initial register A outputs the program"))
+    }
+}
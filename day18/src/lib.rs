@@ -0,0 +1,34 @@
+use std::fmt::Display;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::mem::Memory;
+
+pub mod mem;
+
+const BOUND: usize = 70;
+const FALLEN: usize = 1024;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Memory;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("146"),
+        part2: Expected::Answer("2,0"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Memory::load(input, BOUND)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.steps(FALLEN))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        let block = input.block();
+        Ok(format!("{},{}", block.x, block.y))
+    }
+}
@@ -1,13 +1,9 @@
 use aocrs::input::get_text;
-use crate::mem::Memory;
-
-mod mem;
+use aocrs::solution::Solution;
+use day18::Day18;
 
 fn main() {
-    let input = get_text("./day18/input.txt").unwrap();
-    let memory = Memory::load(&input, 70).unwrap();
-    println!("part 1: {}", memory.steps(1024));     // 146
-
-    let block = memory.block();
-    println!("part 2: {},{}", block.x, block.y);        // 2,0
+    let input = Day18::parse(&get_text("./day18/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day18::part1(&input).unwrap());
+    println!("part 2: {}", Day18::part2(&input).unwrap());
 }
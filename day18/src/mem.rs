@@ -1,9 +1,10 @@
-use std::collections::{HashSet, VecDeque};
-use std::num::ParseIntError;
-use std::str::FromStr;
+use aocrs::error::Error;
 use aocrs::geo::area::Area;
+use aocrs::geo::bit_grid::BitGrid;
 use aocrs::geo::direction::Direction;
 use aocrs::geo::pos::PosIdx;
+use aocrs::input::{parse_lines, pos};
+use aocrs::search::bfs;
 
 #[derive(Clone, Debug)]
 pub struct Memory {
@@ -13,13 +14,8 @@ pub struct Memory {
 }
 
 impl Memory {
-    pub fn load(s: &str, bound: usize) -> Result<Self, ParseIntError> {
-        let mut corrupted = Vec::with_capacity(5000);
-        let lines: Vec<_> = s.trim().lines().collect();
-        for line in lines {
-            let nums: Vec<_> = line.split(",").collect();
-            corrupted.push(PosIdx::new(usize::from_str(nums[0])?, usize::from_str(nums[1])?));
-        }
+    pub fn load(s: &str, bound: usize) -> Result<Self, Error> {
+        let corrupted = parse_lines(s.trim(), pos)?;
         Ok(Self { corrupted, start: PosIdx::origin(), end: PosIdx::new(bound, bound) })
     }
 
@@ -43,23 +39,14 @@ impl Memory {
 
     fn bfs(&self, size: usize) -> Option<usize> {
         let area = Area::new(self.end.x, self.end.y, 0, 0).unwrap();
-        let corrupted: HashSet<PosIdx> = HashSet::from_iter(self.corrupted.iter().copied().take(size));
-        let mut visited: HashSet<PosIdx> = HashSet::with_capacity(area.size());
-        let mut queue = VecDeque::from(vec![(self.start, 0)]);
-        while let Some((pos, steps)) = queue.pop_front() {
-            if !visited.insert(pos) {
-                continue;
-            }
-            if pos == self.end {
-                return Some(steps);
-            }
-
-            for neighbour in area.neighbours(&pos, 1, Direction::cross()) {
-                if !corrupted.contains(&neighbour) {
-                    queue.push_back((neighbour, steps + 1));
-                }
-            }
+        let mut corrupted = BitGrid::new(area.cols(), area.rows());
+        for pos in self.corrupted.iter().take(size) {
+            corrupted.insert(pos);
         }
-        None
+        bfs(
+            self.start,
+            |pos| area.neighbours(pos, 1, Direction::cross()).filter(|&x| !corrupted[x]).collect::<Vec<_>>(),
+            |&pos| pos == self.end,
+        ).goal_cost()
     }
 }
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::patterns::Patterns;
+
+pub mod patterns;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Patterns;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("6"),
+        part2: Expected::Answer("16"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Patterns::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.possible())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.all_ways())
+    }
+}
@@ -1,12 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::patterns::Patterns;
-
-mod patterns;
+use aocrs::solution::Solution;
+use day19::Day19;
 
 fn main() {
-    let input = get_text("./day19/input.txt").unwrap();
-    let patterns = Patterns::from_str(&input.trim().replace("\r\n", "\n")).unwrap();
-    println!("part 1: {}", patterns.possible());        // 6
-    println!("part 2: {}", patterns.all_ways());        // 16
+    let input = Day19::parse(&get_text("./day19/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day19::part1(&input).unwrap());
+    println!("part 2: {}", Day19::part2(&input).unwrap());
 }
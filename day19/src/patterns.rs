@@ -1,6 +1,9 @@
 use std::cmp::min;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
+use aocrs::input::{list, sections};
+use aocrs::memo::Memo;
 
 #[derive(Clone, Debug)]
 pub struct Patterns {
@@ -10,12 +13,14 @@ pub struct Patterns {
 }
 
 impl FromStr for Patterns {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splits: Vec<_> = s.split("\n\n").collect();
-        let patterns: HashSet<_> = splits[0].split(", ").map(String::from).collect();
-        let designs = splits[1].split("\n").map(String::from).collect();
+        let [patterns, designs] = sections(s)[..] else {
+            return Err(Error::parse("", Reason::Invalid("input, expected patterns and designs sections")));
+        };
+        let patterns: HashSet<String> = list::<String>(patterns.text)?.into_iter().collect();
+        let designs = designs.text.lines().map(String::from).collect();
         let max_pattern = patterns.iter().map(|x| x.len()).max().unwrap_or(0);
         Ok(Self { designs, patterns, max_pattern })
     }
@@ -23,31 +28,26 @@ impl FromStr for Patterns {
 
 impl Patterns {
     pub fn possible(&self) -> usize {
-        let mut cache = HashMap::new();
-        self.designs.iter().filter(|&x| self.possible_count(x, &mut cache) > 0).count()
+        let mut memo = Memo::new();
+        self.designs.iter().filter(|&x| self.possible_count(x, &mut memo) > 0).count()
     }
 
     pub fn all_ways(&self) -> usize {
-        let mut cache = HashMap::new();
-        self.designs.iter().map(|x| self.possible_count(x, &mut cache)).sum()
+        let mut memo = Memo::new();
+        self.designs.iter().map(|x| self.possible_count(x, &mut memo)).sum()
     }
 
-    fn possible_count(&self, design: &str, cache: &mut HashMap<String, usize>) -> usize {
+    /// Returns the number of ways to make a design, caching the count for each suffix across designs.
+    fn possible_count(&self, design: &str, memo: &mut Memo<String, usize>) -> usize {
         if design.is_empty() {
             return 1;
         }
 
-        let mut count = 0;
-        for idx in 0..=min(design.len(), self.max_pattern) {
-            if self.patterns.contains(&design[..idx]) {
-                if cache.contains_key(&design[idx..]) {
-                    count += cache[&design[idx..]];
-                } else {
-                    count += self.possible_count(&design[idx..], cache);
-                }
-            }
-        }
-        cache.insert(String::from(design), count);
-        count
+        memo.get_or_insert_with(String::from(design), |memo| {
+            (1..=min(design.len(), self.max_pattern))
+                .filter(|&idx| self.patterns.contains(&design[..idx]))
+                .map(|idx| self.possible_count(&design[idx..], memo))
+                .sum()
+        })
     }
 }
@@ -1,12 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::map::Map;
-
-mod map;
+use aocrs::solution::Solution;
+use day20::Day20;
 
 fn main() {
-    let input = get_text("./day20/input.txt").unwrap();
-    let map = Map::from_str(&input).unwrap();
-    println!("part 1: {}", map.cheats(2));      // 0 on example input
-    println!("part 2: {}", map.cheats(20));     // 0 on example input
+    let input = Day20::parse(&get_text("./day20/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day20::part1(&input).unwrap());
+    println!("part 2: {}", Day20::part2(&input).unwrap());
 }
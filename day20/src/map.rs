@@ -1,8 +1,10 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
 use std::str::FromStr;
+use aocrs::error::{Error, Reason};
 use aocrs::geo::direction::Direction;
-use aocrs::geo::grid::{CharGrid, ParseGridError};
+use aocrs::geo::grid::CharGrid;
 use aocrs::geo::pos::PosIdx;
+use aocrs::search::bfs;
 
 const START: u8 = b'S';
 const WALL: u8 = b'#';
@@ -14,11 +16,11 @@ pub struct Map {
 }
 
 impl FromStr for Map {
-    type Err = ParseGridError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let grid = CharGrid::from_str(s)?;
-        let start = grid.find(START).unwrap();
+        let start = grid.find(START).ok_or_else(|| Error::parse("", Reason::Missing("start `S`")))?;
         Ok(Self { grid, start })
     }
 }
@@ -40,21 +42,10 @@ impl Map {
 
     fn map_dist(&self) -> HashMap<PosIdx, usize> {
         let area = self.grid.area();
-        let mut queue = VecDeque::from([self.start]);
-        let mut result = HashMap::with_capacity(10000);
-        result.insert(self.start, 0);
-        while let Some(pos) = queue.pop_front() {
-            let neighbours = area
-                .neighbours(&pos, 1, Direction::cross())
-                .filter(|&pos| self.grid[pos] != WALL);
-
-            for neighbour in neighbours {
-                if !result.contains_key(&neighbour) {
-                    queue.push_back(neighbour);
-                    result.insert(neighbour, result[&pos] + 1);
-                }
-            }
-        }
-        result
+        bfs(
+            self.start,
+            |pos| area.neighbours(pos, 1, Direction::cross()).filter(|&x| self.grid[x] != WALL).collect::<Vec<_>>(),
+            |_| false,
+        ).costs
     }
 }
@@ -1,13 +1,14 @@
 use std::collections::HashMap;
-use std::num::ParseIntError;
 use std::str::FromStr;
 use std::sync::LazyLock;
+use aocrs::error::{Error, Reason};
 use aocrs::geo::pos::PosIdx;
 
 static NUMPAD: LazyLock<Vec<Vec<Option<u8>>>> = LazyLock::new(|| {
     vec![
         vec![Some(b'7'), Some(b'8'), Some(b'9')],
         vec![Some(b'4'), Some(b'5'), Some(b'6')],
+        vec![Some(b'1'), Some(b'2'), Some(b'3')],
         vec![None, Some(b'0'), Some(b'A')],
     ]
 });
@@ -19,50 +20,90 @@ static DIRPAD: LazyLock<Vec<Vec<Option<u8>>>> = LazyLock::new(|| {
     ]
 });
 
-static NUMPAD_POS: LazyLock<HashMap<PosIdx, u8>> = LazyLock::new(|| {
-    HashMap::from([
-        (PosIdx::new(0, 0), b'7'),
-        (PosIdx::new(1, 0), b'8'),
-        (PosIdx::new(2, 0), b'9'),
-        (PosIdx::new(0, 1), b'4'),
-        (PosIdx::new(1, 1), b'5'),
-        (PosIdx::new(2, 1), b'6'),
-        (PosIdx::new(0, 2), b'1'),
-        (PosIdx::new(1, 2), b'2'),
-        (PosIdx::new(2, 2), b'3'),
-        (PosIdx::new(1, 3), b'0'),
-        (PosIdx::new(2, 3), b'A'),
-    ])
-});
-
-static DIRPAD_POS: LazyLock<HashMap<PosIdx, u8>> = LazyLock::new(|| {
-    HashMap::from([
-        (PosIdx::new(1, 0), b'^'),
-        (PosIdx::new(2, 0), b'A'),
-        (PosIdx::new(1, 1), b'<'),
-        (PosIdx::new(2, 1), b'v'),
-        (PosIdx::new(1, 2), b'>'),
-    ])
-});
-
 #[derive(Clone, Debug)]
 pub struct Code {
     value: String,
-    numeric: i32,
+    numeric: usize,
 }
 
 impl FromStr for Code {
-    type Err = ParseIntError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let value = String::from(s);
-        let numeric = i32::from_str(&s[0..3])?;
+        let digits = s.get(0..3).ok_or_else(|| Error::parse(s, Reason::Missing("three digit number")))?;
+        let numeric = usize::from_str(digits).map_err(|e| Error::from(e).with_text(s))?;
+        if let Some(idx) = s.bytes().position(|x| position(&NUMPAD, x).is_none()) {
+            return Err(Error::parse(s, Reason::Invalid("key, expected 0-9 or A")).at_column(idx + 1));
+        }
         Ok(Self { value, numeric })
     }
 }
 
 impl Code {
-    pub fn complexity(&self) -> usize {
-        0
+    /// Returns the fewest button presses needed to type the code through `robots` directional keypads, multiplied by
+    /// the numeric part of the code.
+    pub fn complexity(&self, robots: usize) -> usize {
+        let mut cache = HashMap::new();
+        let presses: usize = pairs(self.value.as_bytes())
+            .map(|(from, to)| {
+                paths(&NUMPAD, from, to).iter().map(|path| presses(path, robots, &mut cache)).min().unwrap_or_default()
+            })
+            .sum();
+        presses * self.numeric
+    }
+}
+
+/// Returns the fewest button presses needed to type `keys` on a directional keypad which is operated through
+/// `robots` more directional keypads.
+fn presses(keys: &[u8], robots: usize, cache: &mut HashMap<(u8, u8, usize), usize>) -> usize {
+    if robots == 0 {
+        return keys.len();
+    }
+
+    let mut result = 0;
+    for (from, to) in pairs(keys) {
+        if let Some(&count) = cache.get(&(from, to, robots)) {
+            result += count;
+            continue;
+        }
+
+        let count = paths(&DIRPAD, from, to)
+            .iter()
+            .map(|path| presses(path, robots - 1, cache))
+            .min()
+            .unwrap_or_default();
+        cache.insert((from, to, robots), count);
+        result += count;
     }
+    result
+}
+
+/// Returns the moves between consecutive keys, starting from `A`.
+fn pairs(keys: &[u8]) -> impl Iterator<Item = (u8, u8)> + '_ {
+    std::iter::once(b'A').chain(keys.iter().copied()).zip(keys.iter().copied())
+}
+
+/// Returns the directional key sequences which move from one key of a keypad to another and press it, either
+/// horizontally first or vertically first, avoiding the gap.
+fn paths(pad: &[Vec<Option<u8>>], from: u8, to: u8) -> Vec<Vec<u8>> {
+    let start = position(pad, from).unwrap();
+    let end = position(pad, to).unwrap();
+    let horizontal = if end.x < start.x { vec![b'<'; start.x - end.x] } else { vec![b'>'; end.x - start.x] };
+    let vertical = if end.y < start.y { vec![b'^'; start.y - end.y] } else { vec![b'v'; end.y - start.y] };
+
+    let mut result = Vec::new();
+    if pad[start.y][end.x].is_some() {
+        result.push([&horizontal[..], &vertical, b"A"].concat());
+    }
+    if pad[end.y][start.x].is_some() && !horizontal.is_empty() && !vertical.is_empty() {
+        result.push([&vertical[..], &horizontal, b"A"].concat());
+    }
+    result
+}
+
+fn position(pad: &[Vec<Option<u8>>], key: u8) -> Option<PosIdx> {
+    pad.iter().enumerate().find_map(|(y, row)| {
+        row.iter().position(|&x| x == Some(key)).map(|x| PosIdx::new(x, y))
+    })
 }
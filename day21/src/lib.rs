@@ -0,0 +1,35 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use aocrs::error::Error;
+use aocrs::solution::{Example, Expected, Solution};
+use crate::code::Code;
+
+pub mod code;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Code>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../example.txt"),
+        part1: Expected::Answer("126384"),
+        part2: Expected::Answer("154115708116294"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| Code::from_str(line).map_err(|e| e.at_line(idx + 1)))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.iter().map(|x| x.complexity(2)).sum::<usize>())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.iter().map(|x| x.complexity(25)).sum::<usize>())
+    }
+}
@@ -1,13 +1,9 @@
-use std::str::FromStr;
 use aocrs::input::get_text;
-use crate::code::Code;
-
-mod code;
+use aocrs::solution::Solution;
+use day21::Day21;
 
 fn main() {
-    let input = get_text("./day21/input.txt").unwrap();
-    let codes: Vec<_> = input.lines().filter_map(|x| Code::from_str(x).ok()).collect();
-    let complexity: usize = codes.iter().map(Code::complexity).sum();
-    println!("part 1: {}", complexity);
-    println!("part 2: {}", 0);
+    let input = Day21::parse(&get_text("./day21/input.txt").unwrap()).unwrap();
+    println!("part 1: {}", Day21::part1(&input).unwrap());
+    println!("part 2: {}", Day21::part2(&input).unwrap());
 }